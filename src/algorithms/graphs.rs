@@ -1,2 +1,6 @@
+pub mod connectivity;
+pub mod scc;
 pub mod search;
+pub mod spanning_forest;
+pub mod subgraphs;
 pub mod topological_ordering;
@@ -0,0 +1,180 @@
+//! Articulation points and bridges of undirected graphs with Hopcroft and Tarjan's algorithm \[1\].
+//!
+//! Undirected graphs are expected to be stored with both directed edges for each undirected edge.
+//! The implementation is iterative, so it doesn't overflow the call stack on large graphs.
+//!
+//! \[1\] J. Hopcroft and R. Tarjan, “Algorithm 447: Efficient Algorithms for Graph Manipulation,” Commun. ACM, vol. 16, no. 6, pp. 372–378, 1973, doi: [10.1145/362248.362272](https://doi.org/10.1145/362248.362272).
+
+use crate::data_structures::graphs::{DirectedGraph, Direction, Index};
+
+const UNVISITED: usize = usize::MAX;
+
+/// Returns the articulation points (cut vertices) of the graph in increasing order.
+pub fn articulation_points<I: Index, G: DirectedGraph<I>>(graph: &G) -> Vec<I> {
+    let search = LowPoints::run(graph);
+    I::new(0)
+        .range(graph.num_vertices())
+        .filter(|v| search.is_articulation_point[v.index()])
+        .collect()
+}
+
+/// Returns the bridges of the graph in increasing order.
+///
+/// Each bridge is reported once as `(u, v)` with `u < v`.
+pub fn bridges<I: Index, G: DirectedGraph<I>>(graph: &G) -> Vec<(I, I)> {
+    let mut bridges = LowPoints::run(graph).bridges;
+    bridges.sort_unstable();
+    bridges
+}
+
+struct LowPoints<'a, I: Index, G: DirectedGraph<I>> {
+    graph: &'a G,
+    /// order in which the vertices were discovered
+    discovery: Vec<usize>,
+    /// smallest discovery time reachable via the dfs subtree and at most one non-tree edge
+    low: Vec<usize>,
+    next_discovery: usize,
+    /// replaces the recursion: vertices currently being visited
+    call_stack: Vec<Frame<'a, I>>,
+    is_articulation_point: Vec<bool>,
+    bridges: Vec<(I, I)>,
+}
+
+struct Frame<'a, I: Index> {
+    v: I,
+    /// dfs parent of `v`; `None` for the root
+    parent: Option<I>,
+    /// whether the edge back to the parent has been skipped already
+    skipped_parent: bool,
+    /// remaining neighbors of `v`
+    neighbors: Box<dyn Iterator<Item = I> + 'a>,
+}
+
+impl<'a, I: Index, G: DirectedGraph<I>> LowPoints<'a, I, G> {
+    fn run(graph: &'a G) -> Self {
+        let n = graph.num_vertices().index();
+        let mut search = Self {
+            graph,
+            discovery: vec![UNVISITED; n],
+            low: vec![UNVISITED; n],
+            next_discovery: 0,
+            call_stack: Vec::new(),
+            is_articulation_point: vec![false; n],
+            bridges: Vec::new(),
+        };
+        for root in I::new(0).range(graph.num_vertices()) {
+            if search.discovery[root.index()] == UNVISITED {
+                search.visit_from(root);
+            }
+        }
+        search
+    }
+
+    fn visit(&mut self, v: I, parent: Option<I>) {
+        self.discovery[v.index()] = self.next_discovery;
+        self.low[v.index()] = self.next_discovery;
+        self.next_discovery += 1;
+        self.call_stack.push(Frame {
+            v,
+            parent,
+            skipped_parent: false,
+            neighbors: self.graph.neighbors(v, Direction::OUT),
+        });
+    }
+
+    fn visit_from(&mut self, root: I) {
+        let mut root_children = 0;
+        self.visit(root, None);
+
+        while let Some(frame) = self.call_stack.last_mut() {
+            let v = frame.v;
+            match frame.neighbors.next() {
+                // only skip one edge back to the parent, so parallel edges are no bridges
+                Some(w) if Some(w) == frame.parent && !frame.skipped_parent => {
+                    frame.skipped_parent = true
+                }
+                Some(w) if self.discovery[w.index()] == UNVISITED => {
+                    if v == root {
+                        root_children += 1;
+                    }
+                    self.visit(w, Some(v));
+                }
+                Some(w) => {
+                    self.low[v.index()] = self.low[v.index()].min(self.discovery[w.index()]);
+                }
+                None => {
+                    self.call_stack.pop();
+                    if let Some(Frame { v: parent, .. }) = self.call_stack.last() {
+                        let parent = *parent;
+                        self.low[parent.index()] =
+                            self.low[parent.index()].min(self.low[v.index()]);
+
+                        if self.low[v.index()] > self.discovery[parent.index()] {
+                            self.bridges.push((parent.min(v), parent.max(v)));
+                        }
+                        if parent != root && self.low[v.index()] >= self.discovery[parent.index()] {
+                            self.is_articulation_point[parent.index()] = true;
+                        }
+                    }
+                }
+            }
+        }
+
+        self.is_articulation_point[root.index()] = root_children > 1;
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::data_structures::graphs::{DirectedAdjacencyArraysGraph, DirectedEdgeListGraph};
+
+    use super::*;
+
+    // two triangles 0-1-2 and 3-4-5 connected by the bridge 2-3, plus the pendant edge 5-6
+    // and the isolated vertex 7
+    const UNDIRECTED_EDGES: [(u32, u32); 8] = [
+        (0, 1),
+        (1, 2),
+        (2, 0),
+        (2, 3),
+        (3, 4),
+        (4, 5),
+        (5, 3),
+        (5, 6),
+    ];
+
+    fn symmetric_graph(n: u32, edges: &[(u32, u32)]) -> DirectedAdjacencyArraysGraph<u32> {
+        let edges: Vec<(u32, u32)> = edges.iter().flat_map(|&(u, v)| [(u, v), (v, u)]).collect();
+        DirectedAdjacencyArraysGraph::from(&DirectedEdgeListGraph::new(n, edges.into_boxed_slice()))
+    }
+
+    #[test]
+    fn test_articulation_points() {
+        let graph = symmetric_graph(8, &UNDIRECTED_EDGES);
+        assert_eq!(articulation_points(&graph), [2, 3, 5]);
+    }
+
+    #[test]
+    fn test_bridges() {
+        let graph = symmetric_graph(8, &UNDIRECTED_EDGES);
+        assert_eq!(bridges(&graph), [(2, 3), (5, 6)]);
+    }
+
+    #[test]
+    fn test_parallel_edges() {
+        let graph = symmetric_graph(3, &[(0, 1), (0, 1), (1, 2)]);
+        assert_eq!(bridges(&graph), [(1, 2)]);
+        assert_eq!(articulation_points(&graph), [1]);
+    }
+
+    #[test]
+    fn test_long_path() {
+        // deep enough to overflow the stack of a recursive implementation
+        let n = 200_000;
+        let edges: Vec<(u32, u32)> = (1..n).map(|v| (v - 1, v)).collect();
+        let graph = symmetric_graph(n, &edges);
+
+        assert_eq!(articulation_points(&graph), (1..n - 1).collect::<Vec<_>>());
+        assert_eq!(bridges(&graph), edges);
+    }
+}
@@ -0,0 +1,189 @@
+//! Strongly connected components with Tarjan's algorithm \[1\].
+//!
+//! The implementation is iterative, so it doesn't overflow the call stack on large graphs.
+//!
+//! \[1\] R. Tarjan, “Depth-First Search and Linear Graph Algorithms,” SIAM J. Comput., vol. 1, no. 2, pp. 146–160, 1972, doi: [10.1137/0201010](https://doi.org/10.1137/0201010).
+
+use crate::data_structures::graphs::{DirectedGraph, Direction, Index};
+
+const UNVISITED: usize = usize::MAX;
+
+/// Returns the strongly connected components of the graph.
+///
+/// The components are in reverse topological order of the condensation,
+/// i.e. all edges between different components point from a later to an earlier component.
+pub fn strongly_connected_components<I: Index, G: DirectedGraph<I>>(graph: &G) -> Vec<Vec<I>> {
+    let mut tarjan = Tarjan::new(graph);
+    for root in I::new(0).range(graph.num_vertices()) {
+        if tarjan.index[root.index()] == UNVISITED {
+            tarjan.visit_from(root);
+        }
+    }
+    tarjan.components
+}
+
+struct Tarjan<'a, I: Index, G: DirectedGraph<I>> {
+    graph: &'a G,
+    /// order in which the vertices were visited
+    index: Vec<usize>,
+    /// smallest index reachable via the dfs subtree and at most one further edge
+    low_link: Vec<usize>,
+    on_stack: Vec<bool>,
+    next_index: usize,
+    /// vertices of the components that are not yet completed
+    stack: Vec<I>,
+    /// replaces the recursion: vertices currently being visited together with their remaining out-neighbors
+    call_stack: Vec<(I, Box<dyn Iterator<Item = I> + 'a>)>,
+    components: Vec<Vec<I>>,
+}
+
+impl<'a, I: Index, G: DirectedGraph<I>> Tarjan<'a, I, G> {
+    fn new(graph: &'a G) -> Self {
+        let n = graph.num_vertices().index();
+        Self {
+            graph,
+            index: vec![UNVISITED; n],
+            low_link: vec![UNVISITED; n],
+            on_stack: vec![false; n],
+            next_index: 0,
+            stack: Vec::new(),
+            call_stack: Vec::new(),
+            components: Vec::new(),
+        }
+    }
+
+    fn visit(&mut self, v: I) {
+        self.index[v.index()] = self.next_index;
+        self.low_link[v.index()] = self.next_index;
+        self.next_index += 1;
+        self.stack.push(v);
+        self.on_stack[v.index()] = true;
+        self.call_stack
+            .push((v, self.graph.neighbors(v, Direction::OUT)));
+    }
+
+    fn visit_from(&mut self, root: I) {
+        self.visit(root);
+
+        while let Some((v, neighbors)) = self.call_stack.last_mut() {
+            let v = *v;
+            match neighbors.next() {
+                Some(w) if self.index[w.index()] == UNVISITED => self.visit(w),
+                Some(w) => {
+                    if self.on_stack[w.index()] {
+                        self.low_link[v.index()] =
+                            self.low_link[v.index()].min(self.index[w.index()]);
+                    }
+                }
+                None => {
+                    self.call_stack.pop();
+                    if let Some((parent, _)) = self.call_stack.last() {
+                        self.low_link[parent.index()] =
+                            self.low_link[parent.index()].min(self.low_link[v.index()]);
+                    }
+
+                    // v is the root of a component
+                    if self.low_link[v.index()] == self.index[v.index()] {
+                        let mut component = Vec::new();
+                        while let Some(w) = self.stack.pop() {
+                            self.on_stack[w.index()] = false;
+                            component.push(w);
+                            if w == v {
+                                break;
+                            }
+                        }
+                        self.components.push(component);
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Returns whether the graph has a directed cycle, including loops.
+pub fn has_cycle<I: Index, G: DirectedGraph<I>>(graph: &G) -> bool {
+    strongly_connected_components(graph)
+        .iter()
+        .any(|component| match component[..] {
+            [v] => graph.has_edge(v, v),
+            _ => true,
+        })
+}
+
+#[cfg(test)]
+mod test {
+    use crate::data_structures::graphs::{DirectedAdjacencyArraysGraph, DirectedEdgeListGraph};
+
+    use super::*;
+
+    // CRLS (4th edition) Figure 20.4
+    const EDGES: [(u32, u32); 8] = [
+        (0, 1),
+        (0, 3),
+        (1, 4),
+        (2, 4),
+        (2, 5),
+        (3, 1),
+        (4, 3),
+        (5, 5),
+    ];
+
+    // DAG from the topological ordering tests
+    const DAG_EDGES: [(u32, u32); 5] = [(0, 3), (1, 2), (3, 4), (3, 1), (4, 2)];
+
+    fn sorted(mut components: Vec<Vec<u32>>) -> Vec<Vec<u32>> {
+        components.iter_mut().for_each(|c| c.sort_unstable());
+        components
+    }
+
+    #[test]
+    fn test_strongly_connected_components() {
+        let graph = DirectedEdgeListGraph::new(6, Box::new(EDGES));
+        let graph = DirectedAdjacencyArraysGraph::from(&graph);
+
+        let components = strongly_connected_components(&graph);
+        assert_eq!(
+            sorted(components.clone()),
+            [vec![1, 3, 4], vec![0], vec![5], vec![2]]
+        );
+
+        // reverse topological order of the condensation
+        let mut component_of = [0; 6];
+        for (i, component) in components.iter().enumerate() {
+            for &v in component {
+                component_of[v as usize] = i;
+            }
+        }
+        for (u, v) in EDGES {
+            assert!(component_of[u as usize] >= component_of[v as usize]);
+        }
+    }
+
+    #[test]
+    fn test_has_cycle() {
+        let graph = DirectedEdgeListGraph::new(6, Box::new(EDGES));
+        assert!(has_cycle(&graph));
+
+        let graph = DirectedEdgeListGraph::new(5, Box::new(DAG_EDGES));
+        assert!(!has_cycle(&graph));
+        assert_eq!(strongly_connected_components(&graph).len(), 5);
+
+        // a single loop is a cycle
+        let graph = DirectedEdgeListGraph::new(2, Box::new([(0, 1), (1, 1)]));
+        assert!(has_cycle(&graph));
+    }
+
+    #[test]
+    fn test_long_cycle() {
+        // deep enough to overflow the stack of a recursive implementation
+        let n = 200_000;
+        let edges: Vec<(u32, u32)> = (0..n).map(|v| (v, (v + 1) % n)).collect();
+        let graph = DirectedEdgeListGraph::new(n, edges.into_boxed_slice());
+        let graph = DirectedAdjacencyArraysGraph::from(&graph);
+
+        let components = strongly_connected_components(&graph);
+        assert_eq!(components.len(), 1);
+        assert_eq!(components[0].len(), n as usize);
+        assert!(has_cycle(&graph));
+    }
+}
@@ -1,4 +1,4 @@
-use std::ops::ControlFlow;
+use std::{collections::VecDeque, ops::ControlFlow};
 
 use crate::data_structures::graphs::{DirectedGraph, Direction, Index};
 
@@ -102,6 +102,102 @@ impl<'a, I: Index> IDFS<'a, I> {
     }
 }
 
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum BfsEvent<I: Index> {
+    /// A new BFS tree is started at this vertex.
+    Root(I),
+    /// The second vertex has been discovered via the edge from the first vertex.
+    TreeEdge(I, I),
+    /// The edge leads to an already discovered vertex.
+    NonTreeEdge(I, I),
+}
+
+/// Incremental breadth first search
+///
+/// Just like [IDFS], the search restarts at the next undiscovered vertex once
+/// all vertices reachable from the current root have been visited.
+pub struct IBFS<'a, I: Index> {
+    colors: Vec<Color>,
+    queue: VecDeque<I>,
+    current: Option<(I, Box<dyn Iterator<Item = I> + 'a>)>,
+    bfs_loop: Box<dyn Iterator<Item = I>>,
+}
+
+impl<'a, I: Index> IBFS<'a, I> {
+    pub fn new(num_vertices: I) -> Self {
+        Self {
+            colors: vec![Color::White; num_vertices.index()],
+            queue: VecDeque::new(),
+            current: None,
+            bfs_loop: Box::new(I::new(0).range(num_vertices)),
+        }
+    }
+
+    pub fn next<G: DirectedGraph<I>>(&mut self, graph: &'a G) -> Option<BfsEvent<I>> {
+        loop {
+            // continue scanning the neighbors of the current vertex
+            if let Some((u, neighbors)) = self.current.as_mut() {
+                let u = *u;
+                if let Some(v) = neighbors.next() {
+                    return match self.colors[v.index()] {
+                        Color::White => {
+                            self.colors[v.index()] = Color::Gray;
+                            self.queue.push_back(v);
+                            Some(BfsEvent::TreeEdge(u, v))
+                        }
+                        Color::Gray | Color::Black => Some(BfsEvent::NonTreeEdge(u, v)),
+                    };
+                }
+                // all neighbors scanned
+                self.colors[u.index()] = Color::Black;
+                self.current = None;
+            }
+
+            // take the next discovered vertex from the queue
+            if let Some(u) = self.queue.pop_front() {
+                self.current = Some((u, graph.neighbors(u, Direction::OUT)));
+                continue;
+            }
+
+            // find the next root vertex
+            for u in self.bfs_loop.by_ref() {
+                if self.colors[u.index()] == Color::White {
+                    self.colors[u.index()] = Color::Gray;
+                    self.queue.push_back(u);
+                    return Some(BfsEvent::Root(u));
+                }
+            }
+
+            return None; // we've visited all vertices
+        }
+    }
+}
+
+/// Compute a 2-coloring of an undirected graph with [IBFS], if there is one.
+///
+/// Undirected graphs are expected to be stored with both directed edges for each undirected edge.
+/// Every BFS tree is colored alternately starting with `false` at its root.
+/// Returns `None` as soon as an edge between two vertices of the same color is found,
+/// i.e. if the graph contains an odd cycle (including loops) and thus is not bipartite.
+pub fn two_coloring<I: Index, G: DirectedGraph<I>>(graph: &G) -> Option<Vec<bool>> {
+    let mut colors = vec![false; graph.num_vertices().index()];
+    let mut bfs = IBFS::new(graph.num_vertices());
+
+    while let Some(e) = bfs.next(graph) {
+        match e {
+            BfsEvent::Root(v) => colors[v.index()] = false,
+            BfsEvent::TreeEdge(u, v) => colors[v.index()] = !colors[u.index()],
+            BfsEvent::NonTreeEdge(u, v) => {
+                if colors[u.index()] == colors[v.index()] {
+                    return None;
+                }
+            }
+        }
+    }
+
+    Some(colors)
+}
+
 /// A recursive DFS implementation as presented in CRLS: Introduction to Algorithms
 pub fn dfs<I: Index, G: DirectedGraph<I>, B>(
     graph: &G,
@@ -117,6 +213,24 @@ pub fn dfs<I: Index, G: DirectedGraph<I>, B>(
     ControlFlow::Continue(())
 }
 
+/// Check whether there is a path from `source` to `target`.
+///
+/// Runs a DFS from `source` that stops as soon as `target` is discovered.
+pub fn reachable<I: Index, G: DirectedGraph<I>>(graph: &G, source: I, target: I) -> bool {
+    let mut colors = vec![Color::White; graph.num_vertices().index()];
+
+    dfs_visit(
+        graph,
+        source,
+        &mut |e| match e {
+            DfsEvent::Discovered(v) if v == target => ControlFlow::Break(()),
+            _ => ControlFlow::Continue(()),
+        },
+        &mut colors,
+    )
+    .is_break()
+}
+
 fn dfs_visit<I: Index, G: DirectedGraph<I>, B>(
     graph: &G,
     u: I,
@@ -149,7 +263,11 @@ fn dfs_visit<I: Index, G: DirectedGraph<I>, B>(
 mod test {
     use crate::data_structures::graphs::{DirectedAdjacencyArraysGraph, DirectedEdgeListGraph};
 
-    use super::{DfsEvent::BackEdge, DfsEvent::Discovered, DfsEvent::Finished, *};
+    use super::{
+        BfsEvent::{NonTreeEdge, Root, TreeEdge},
+        DfsEvent::{BackEdge, Discovered, Finished},
+        *,
+    };
 
     /// DFS example in Figure 20.4 of CRLS 4th edition
     const CRLS_20_4_EDGES: [(u32, u32); 8] = [
@@ -202,7 +320,7 @@ mod test {
 
         let mut events = Vec::new();
 
-        dfs(&graph, &mut |e| {
+        let _ = dfs(&graph, &mut |e| {
             events.push(e);
             ControlFlow::<()>::Continue(())
         });
@@ -227,4 +345,69 @@ mod test {
             ]
         );
     }
+
+    #[test]
+    fn test_bfs_enumeration_crls_20_4() {
+        let graph = DirectedEdgeListGraph::new(6, Box::new(CRLS_20_4_EDGES));
+        let graph = DirectedAdjacencyArraysGraph::from(&graph);
+        let mut bfs = IBFS::new(graph.num_vertices());
+
+        let mut events = Vec::new();
+        while let Some(e) = bfs.next(&graph) {
+            events.push(e);
+        }
+
+        assert_eq!(
+            events,
+            [
+                Root(0),
+                TreeEdge(0, 1),
+                TreeEdge(0, 3),
+                TreeEdge(1, 4),
+                NonTreeEdge(3, 1),
+                NonTreeEdge(4, 3),
+                Root(2),
+                NonTreeEdge(2, 4),
+                TreeEdge(2, 5),
+                NonTreeEdge(5, 5),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_reachable_crls_20_4() {
+        let graph = DirectedEdgeListGraph::new(6, Box::new(CRLS_20_4_EDGES));
+        let graph = DirectedAdjacencyArraysGraph::from(&graph);
+
+        assert!(reachable(&graph, 0, 3));
+        assert!(reachable(&graph, 2, 1));
+        assert!(reachable(&graph, 5, 5));
+        assert!(!reachable(&graph, 0, 2));
+        assert!(!reachable(&graph, 3, 0));
+    }
+
+    #[test]
+    fn test_two_coloring() {
+        // even cycle 0-1-2-3 and the separate edge 4-5
+        let edges = [(0, 1), (1, 2), (2, 3), (3, 0), (4, 5)];
+        let edges: Vec<(u32, u32)> = edges.iter().flat_map(|&(u, v)| [(u, v), (v, u)]).collect();
+        let graph = DirectedEdgeListGraph::new(6, edges.clone().into_boxed_slice());
+        let graph = DirectedAdjacencyArraysGraph::from(&graph);
+
+        let colors = two_coloring(&graph).unwrap();
+        assert_eq!(colors.len(), 6);
+        for (u, v) in edges {
+            assert_ne!(colors[u as usize], colors[v as usize]);
+        }
+    }
+
+    #[test]
+    fn test_two_coloring_odd_cycle() {
+        let edges = [(0, 1), (1, 2), (2, 0)];
+        let edges: Vec<(u32, u32)> = edges.iter().flat_map(|&(u, v)| [(u, v), (v, u)]).collect();
+        let graph = DirectedEdgeListGraph::new(4, edges.into_boxed_slice());
+        let graph = DirectedAdjacencyArraysGraph::from(&graph);
+
+        assert_eq!(two_coloring(&graph), None);
+    }
 }
@@ -0,0 +1,99 @@
+use crate::data_structures::graphs::{DirectedGraph, Index};
+
+use super::search::{BfsEvent, IBFS};
+
+/// Enumerate the edges of a spanning forest with incremental BFS.
+///
+/// Undirected graphs are expected to be stored with both directed edges for each undirected edge.
+/// Each emitted partial is a tree edge `(parent, child)`; every vertex is emitted as child at most once,
+/// the roots of the BFS trees (one per connected component) are never emitted as child.
+pub struct SpanningForest<'a, I: Index, G: DirectedGraph<I>> {
+    graph: &'a G,
+    bfs: IBFS<'a, I>,
+}
+
+impl<'a, I: Index, G: DirectedGraph<I>> SpanningForest<'a, I, G> {
+    pub fn new(graph: &'a G) -> Self {
+        Self {
+            graph,
+            bfs: IBFS::new(graph.num_vertices()),
+        }
+    }
+}
+
+impl<I: Index, G: DirectedGraph<I>> Iterator for SpanningForest<'_, I, G> {
+    type Item = (I, I);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some(e) = self.bfs.next(self.graph) {
+            if let BfsEvent::TreeEdge(parent, child) = e {
+                return Some((parent, child));
+            }
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::data_structures::graphs::{DirectedAdjacencyArraysGraph, DirectedEdgeListGraph};
+
+    use super::*;
+
+    // two components {0, 1, 2, 3} and {4, 5, 6} with cycles in both;
+    // each edge is stored in both directions, plus a self-loop at 6
+    const EDGES: [(u32, u32); 15] = [
+        (0, 1),
+        (1, 0),
+        (1, 2),
+        (2, 1),
+        (2, 0),
+        (0, 2),
+        (2, 3),
+        (3, 2),
+        (4, 5),
+        (5, 4),
+        (5, 6),
+        (6, 5),
+        (6, 4),
+        (4, 6),
+        (6, 6),
+    ];
+
+    #[test]
+    fn test_spanning_forest() {
+        let graph = DirectedEdgeListGraph::new(7, EDGES.into());
+        let graph = DirectedAdjacencyArraysGraph::from(&graph);
+
+        let forest: Vec<(u32, u32)> = SpanningForest::new(&graph).collect();
+
+        // n - (number of components) tree edges
+        assert_eq!(forest.len(), 5);
+        assert!(
+            forest.iter().all(|e| EDGES.contains(e)),
+            "Forest contains an edge that is not in the graph."
+        );
+
+        let mut parent = [None; 7];
+        for &(u, v) in forest.iter() {
+            assert!(
+                parent[v as usize].is_none(),
+                "Vertex {} is a child twice.",
+                v
+            );
+            parent[v as usize] = Some(u);
+        }
+        // the roots are the first vertices of each component
+        assert!(parent[0].is_none());
+        assert!(parent[4].is_none());
+
+        // following parent links from each vertex has to end in the root of its component
+        for (v, root) in [(1, 0), (2, 0), (3, 0), (5, 4), (6, 4)] {
+            let mut u = v;
+            while let Some(p) = parent[u] {
+                u = p as usize;
+            }
+            assert_eq!(u, root);
+        }
+    }
+}
@@ -0,0 +1,165 @@
+//! Subgraphs of directed graphs with compact vertex ids
+
+use crate::data_structures::graphs::{
+    DirectedAdjacencyArraysGraph, DirectedEdgeListGraph, DirectedGraph, Direction, Index,
+};
+
+/// Returns the subgraph induced by `vertices`, i.e. all edges with both endpoints in `vertices`.
+///
+/// The vertex `vertices[i]` becomes vertex `i` of the subgraph, so `vertices` also maps the subgraph's
+/// vertices back to the original ones; it is returned as second component. The edges keep their
+/// relative order in the out-adjacencies of each vertex.
+///
+/// # Panics
+///
+/// Panics if `vertices` contains a vertex twice or a vertex that is not part of the graph.
+pub fn induced_subgraph<I: Index, G: DirectedGraph<I>>(
+    graph: &G,
+    vertices: &[I],
+) -> (DirectedAdjacencyArraysGraph<I>, Vec<I>) {
+    let mut new_ids: Vec<Option<I>> = vec![None; graph.num_vertices().index()];
+    for (i, &v) in vertices.iter().enumerate() {
+        assert!(
+            new_ids[v.index()].replace(I::new(i)).is_none(),
+            "Vertex {} is contained twice.",
+            v
+        );
+    }
+
+    let edges: Vec<(I, I)> = vertices
+        .iter()
+        .enumerate()
+        .flat_map(|(u, &v)| {
+            let new_ids = &new_ids;
+            graph
+                .neighbors(v, Direction::OUT)
+                .filter_map(move |w| new_ids[w.index()].map(|w| (I::new(u), w)))
+        })
+        .collect();
+
+    let subgraph = DirectedEdgeListGraph::new(I::new(vertices.len()), edges.into_boxed_slice());
+    (
+        DirectedAdjacencyArraysGraph::from(&subgraph),
+        vertices.to_vec(),
+    )
+}
+
+/// Returns the graph without isolated vertices, i.e. vertices without in- and out-edges.
+///
+/// The remaining vertices keep their relative order and are numbered consecutively.
+/// The second component maps each new vertex id to the original one.
+/// The out-adjacencies of each vertex keep their order.
+pub fn compact<I: Index, G: DirectedGraph<I>>(
+    graph: &G,
+) -> (DirectedAdjacencyArraysGraph<I>, Vec<I>) {
+    let non_isolated: Vec<I> = I::new(0)
+        .range(graph.num_vertices())
+        .filter(|&v| {
+            graph.degree(v, Direction::OUT) > I::new(0)
+                || graph.degree(v, Direction::IN) > I::new(0)
+        })
+        .collect();
+    induced_subgraph(graph, &non_isolated)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const EDGES: [(u32, u32); 8] = [
+        (0, 1),
+        (0, 3),
+        (1, 4),
+        (2, 4),
+        (2, 5),
+        (3, 1),
+        (4, 3),
+        (5, 5),
+    ];
+
+    fn graph() -> DirectedAdjacencyArraysGraph<u32> {
+        DirectedAdjacencyArraysGraph::from(&DirectedEdgeListGraph::new(6, Box::new(EDGES)))
+    }
+
+    fn edges(graph: &DirectedAdjacencyArraysGraph<u32>) -> Vec<(u32, u32)> {
+        (0..graph.num_vertices())
+            .flat_map(|u| graph.neighbors(u, Direction::OUT).map(move |v| (u, v)))
+            .collect()
+    }
+
+    #[test]
+    fn test_induced_subgraph() {
+        let (subgraph, original) = induced_subgraph(&graph(), &[2, 3, 4]);
+
+        assert_eq!(subgraph.num_vertices(), 3);
+        assert_eq!(original, [2, 3, 4]);
+        // (2, 4) and (4, 3) in the original ids
+        assert_eq!(edges(&subgraph), [(0, 2), (2, 1)]);
+    }
+
+    #[test]
+    fn test_induced_subgraph_order_and_loops() {
+        let (subgraph, original) = induced_subgraph(&graph(), &[5, 2, 4]);
+
+        assert_eq!(original, [5, 2, 4]);
+        assert_eq!(edges(&subgraph), [(0, 0), (1, 2), (1, 0)]);
+        for (u, v) in edges(&subgraph) {
+            assert!(EDGES.contains(&(original[u as usize], original[v as usize])));
+        }
+    }
+
+    #[test]
+    fn test_induced_subgraph_all_and_none() {
+        let (subgraph, _) = induced_subgraph(&graph(), &[0, 1, 2, 3, 4, 5]);
+        assert_eq!(edges(&subgraph), EDGES);
+
+        let (subgraph, original) = induced_subgraph(&graph(), &[]);
+        assert_eq!(subgraph.num_vertices(), 0);
+        assert!(original.is_empty());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_induced_subgraph_duplicate_vertex() {
+        induced_subgraph(&graph(), &[1, 2, 1]);
+    }
+
+    #[test]
+    fn test_compact() {
+        // EDGES with isolated vertices 2 and 7 inserted
+        let relabel = |v: u32| if v < 2 { v } else { v + 1 };
+        let edges = EDGES.map(|(u, v)| (relabel(u), relabel(v)));
+        let graph =
+            DirectedAdjacencyArraysGraph::from(&DirectedEdgeListGraph::new(8, Box::new(edges)));
+
+        let (compacted, original_ids) = compact(&graph);
+
+        assert_eq!(original_ids, [0, 1, 3, 4, 5, 6]);
+        assert_eq!(compacted.num_vertices(), 6);
+        assert_eq!(compacted.num_edges(), 8);
+        assert_eq!(compacted.num_loops(), 1);
+        for v in 0..6 {
+            assert!(compacted
+                .neighbors(v, Direction::OUT)
+                .map(|w| original_ids[w as usize])
+                .eq(graph.neighbors(original_ids[v as usize], Direction::OUT)));
+        }
+        assert_eq!(
+            format!("{}", compacted),
+            format!(
+                "{}",
+                DirectedAdjacencyArraysGraph::from(&DirectedEdgeListGraph::new(6, Box::new(EDGES)))
+            )
+        );
+    }
+
+    #[test]
+    fn test_compact_without_isolated_vertices() {
+        let graph =
+            DirectedAdjacencyArraysGraph::from(&DirectedEdgeListGraph::new(6, Box::new(EDGES)));
+        let (compacted, original_ids) = compact(&graph);
+
+        assert_eq!(original_ids, [0, 1, 2, 3, 4, 5]);
+        assert_eq!(format!("{}", compacted), format!("{}", graph));
+    }
+}
@@ -1,7 +1,10 @@
 use core::fmt;
-use std::ops::ControlFlow;
+use std::{cmp::Reverse, collections::BinaryHeap, ops::ControlFlow};
 
-use crate::data_structures::graphs::{DirectedGraph, Direction, Index};
+use crate::{
+    algorithms::CouldNotComputeError,
+    data_structures::graphs::{DirectedGraph, Direction, Index},
+};
 
 use super::search::{dfs, DfsEvent, IDFS};
 
@@ -10,6 +13,8 @@ pub struct IterativeSourceRemoval<'a, I: Index, DAG: DirectedGraph<I>> {
     in_degrees: Vec<I>,
     sources: Vec<I>,
     num_ordered: I,
+    // set once a cycle was reported, so the iterator ends afterwards
+    done: bool,
 }
 
 impl<'a, I: Index, DAG: DirectedGraph<I>> IterativeSourceRemoval<'a, I, DAG> {
@@ -36,6 +41,7 @@ impl<'a, I: Index, DAG: DirectedGraph<I>> IterativeSourceRemoval<'a, I, DAG> {
             in_degrees,
             sources,
             num_ordered: I::new(0),
+            done: false,
         }
     }
 }
@@ -53,14 +59,82 @@ impl<I: Index, DAG: DirectedGraph<I>> Iterator for IterativeSourceRemoval<'_, I,
             }
             self.num_ordered += I::new(1);
             Some(Ok(source))
-        } else if self.num_ordered == self.graph.num_vertices() {
+        } else if self.done || self.num_ordered == self.graph.num_vertices() {
             None
         } else {
+            self.done = true;
             Some(Err(HasCycles))
         }
     }
 }
 
+/// Kahn's algorithm for topological ordering
+///
+/// Like [`IterativeSourceRemoval`], but the current sources are kept in a min-heap,
+/// so if several vertices have in-degree zero the one with the smallest id comes first.
+/// This makes the order independent of the order of the adjacencies.
+pub struct Kahn<'a, I: Index, DAG: DirectedGraph<I>> {
+    graph: &'a DAG,
+    in_degrees: Vec<I>,
+    sources: BinaryHeap<Reverse<I>>,
+    num_ordered: I,
+    // set once a cycle was reported, so the iterator ends afterwards
+    done: bool,
+}
+
+impl<'a, I: Index, DAG: DirectedGraph<I>> Kahn<'a, I, DAG> {
+    pub fn new(graph: &'a DAG) -> Self {
+        let in_degrees: Vec<I> = I::new(0)
+            .range(graph.num_vertices())
+            .map(|v| graph.degree(v, Direction::IN))
+            .collect();
+
+        let sources: BinaryHeap<Reverse<I>> = in_degrees
+            .iter()
+            .enumerate()
+            .filter(|(_, deg)| **deg == I::new(0))
+            .map(|(v, _)| Reverse(I::new(v)))
+            .collect();
+
+        Self {
+            graph,
+            in_degrees,
+            sources,
+            num_ordered: I::new(0),
+            done: false,
+        }
+    }
+}
+
+impl<I: Index, DAG: DirectedGraph<I>> Iterator for Kahn<'_, I, DAG> {
+    type Item = Result<I, HasCycles>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(Reverse(source)) = self.sources.pop() {
+            for n in self.graph.neighbors(source, Direction::OUT) {
+                self.in_degrees[n.index()] -= I::new(1);
+                if self.in_degrees[n.index()] == I::new(0) {
+                    self.sources.push(Reverse(n));
+                }
+            }
+            self.num_ordered += I::new(1);
+            Some(Ok(source))
+        } else if self.done || self.num_ordered == self.graph.num_vertices() {
+            None
+        } else {
+            self.done = true;
+            Some(Err(HasCycles))
+        }
+    }
+}
+
+/// Compute a topological ordering with Kahn's algorithm, breaking ties by smallest vertex id
+pub fn kahn_topological_order<I: Index, DAG: DirectedGraph<I>>(
+    graph: &DAG,
+) -> Result<Vec<I>, HasCycles> {
+    Kahn::new(graph).collect()
+}
+
 /// Compute a topological ordering with an incremental algorithm for DFS finishing times
 pub fn idfs_finish_time<I: Index, DAG: DirectedGraph<I>>(graph: &DAG) -> Result<Vec<I>, HasCycles> {
     let mut order = vec![I::new(0); graph.num_vertices().index()];
@@ -124,6 +198,14 @@ impl fmt::Display for HasCycles {
 }
 impl std::error::Error for HasCycles {}
 
+impl From<HasCycles> for CouldNotComputeError {
+    fn from(why: HasCycles) -> Self {
+        CouldNotComputeError {
+            reason: why.to_string(),
+        }
+    }
+}
+
 #[cfg(test)]
 mod test {
     use crate::data_structures::graphs::{DirectedAdjacencyArraysGraph, DirectedEdgeListGraph};
@@ -161,12 +243,38 @@ mod test {
         assert_eq!(order.unwrap(), TOPO_ORDER);
     }
 
+    #[test]
+    fn test_kahn() {
+        let graph = DirectedEdgeListGraph::new(5, EDGES.into());
+        let graph = DirectedAdjacencyArraysGraph::from(&graph);
+        let order: Result<Vec<u32>, HasCycles> = kahn_topological_order(&graph);
+        assert_eq!(order.unwrap(), TOPO_ORDER);
+    }
+
+    #[test]
+    fn test_kahn_smallest_source_first() {
+        let graph = DirectedEdgeListGraph::new(5, Box::new([(4, 0), (3, 0), (2, 1)]));
+        let graph = DirectedAdjacencyArraysGraph::from(&graph);
+        let order: Result<Vec<u32>, HasCycles> = kahn_topological_order(&graph);
+        assert_eq!(order.unwrap(), [2, 1, 3, 4, 0]);
+    }
+
     #[test]
     fn test_iterative_source_removal_with_cycle() {
         let graph = DirectedEdgeListGraph::new(5, EDGES_WITH_CYCLE.into());
         let graph = DirectedAdjacencyArraysGraph::from(&graph);
-        let order: Result<Vec<u32>, HasCycles> = IterativeSourceRemoval::new(&graph).collect();
-        assert!(order.is_err_and(|e| e == HasCycles));
+        let order: Vec<Result<u32, HasCycles>> = IterativeSourceRemoval::new(&graph).collect();
+        // the cycle is reported once, then the iteration ends
+        assert_eq!(order, [Ok(0), Err(HasCycles)]);
+    }
+
+    #[test]
+    fn test_kahn_with_cycle() {
+        let graph = DirectedEdgeListGraph::new(5, EDGES_WITH_CYCLE.into());
+        let graph = DirectedAdjacencyArraysGraph::from(&graph);
+        let order: Vec<Result<u32, HasCycles>> = Kahn::new(&graph).collect();
+        // the cycle is reported once, then the iteration ends
+        assert_eq!(order, [Ok(0), Err(HasCycles)]);
     }
 
     #[test]
@@ -1,3 +1,19 @@
 pub mod graphs;
+pub mod noop;
 pub mod scheduling;
 pub mod sorting;
+
+use core::fmt;
+
+/// An algorithm could not compute a solution, e.g. because its input violates the assumptions of the problem.
+#[derive(Debug, PartialEq)]
+pub struct CouldNotComputeError {
+    pub reason: String,
+}
+
+impl fmt::Display for CouldNotComputeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Could not compute a solution: {}", self.reason)
+    }
+}
+impl std::error::Error for CouldNotComputeError {}
@@ -0,0 +1,63 @@
+//! A no-op enumeration as baseline for interpreting delays.
+//!
+//! The enumeration yields the trivial partials `0..n` without doing any work,
+//! so its delays are just the overhead of the measurement loop and of iterating through a boxed iterator.
+
+use crate::experiments::{
+    runner, EnumerationMeasurement, ExperimentAlgorithm, PreparedEnumerationAlgorithm,
+};
+
+pub type AlgorithmType = ExperimentAlgorithm<u32, u32, Vec<u32>>;
+
+/// Enumeration algorithm yielding the partials `0..n` for input `n`
+pub const ENUMERATE_NOOP: AlgorithmType =
+    ExperimentAlgorithm::EnumerationAlgorithm("enum-noop", enumerate_noop);
+
+fn enumerate_noop(n: &u32) -> PreparedEnumerationAlgorithm<'_, u32> {
+    Box::new(0..*n)
+}
+
+/// Measurements of the no-op enumeration through a boxed and through a direct iterator.
+pub struct IterationOverhead {
+    /// Enumeration through `Box<dyn Iterator>`, just as enumeration algorithms are run in experiments
+    pub boxed: EnumerationMeasurement,
+    /// Enumeration through the iterator itself
+    pub direct: EnumerationMeasurement,
+}
+
+/// Measures the no-op enumeration of `n` partials both boxed and direct.
+///
+/// The difference of the delays is the cost of the dynamic dispatch in [`PreparedEnumerationAlgorithm`].
+pub fn iteration_overhead(n: u32) -> IterationOverhead {
+    IterationOverhead {
+        boxed: runner::measure_enumeration(|| enumerate_noop(&n)),
+        direct: runner::measure_enumeration(|| 0..n),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_noop_enumeration() {
+        let ExperimentAlgorithm::EnumerationAlgorithm(_, algorithm) = ENUMERATE_NOOP else {
+            panic!("Expected an enumeration algorithm.");
+        };
+
+        assert!(algorithm(&1000).eq(0..1000));
+        assert_eq!(algorithm(&0).count(), 0);
+    }
+
+    #[test]
+    fn test_iteration_overhead() {
+        let overhead = iteration_overhead(1000);
+
+        for measurement in [overhead.boxed, overhead.direct] {
+            assert_eq!(measurement.delays, 1000);
+            // generous bound, a no-op delay is in the order of tens of ns
+            assert!(measurement.delay_avg < 1_000_000.0);
+            assert!(measurement.first_output <= measurement.total_time);
+        }
+    }
+}
@@ -1,2 +1,295 @@
 pub mod flow_shop;
+pub mod parallel_machines;
 pub mod single_machine;
+
+use std::collections::{HashMap, HashSet};
+
+use crate::data_structures::scheduling_problems::{
+    MachineEnvironment, OperationMachines, SchedulingInstance,
+};
+
+/// A part of a schedule: a job that starts processing on a machine at some time.
+pub trait ScheduleEntry {
+    fn job(&self) -> u32;
+    /// The machine, numbered from 1
+    fn machine(&self) -> u32;
+    fn start_time(&self) -> i64;
+}
+
+/// Checks that the partials form a valid schedule for the instance, in the order they were emitted.
+///
+/// The processing times are determined by [`MachineEnvironment::operation_machines`]. A schedule is valid if
+///
+/// - the partials are emitted with non-decreasing start times,
+/// - every job of the instance is scheduled exactly once on every machine
+///   ([`OperationMachines::EveryMachine`]) or exactly once in total,
+/// - no two jobs overlap on the same machine and no job is processed on two machines at the same time.
+///
+/// Constraints specific to a problem, e.g. release times or precedences, are not checked.
+/// Returns a description of the first violation found otherwise.
+pub fn validate_schedule<Machines, Deadline, ReleaseTime, Precedences, Weight, Partial>(
+    instance: &SchedulingInstance<Machines, i32, Deadline, ReleaseTime, Precedences, Weight>,
+    partials: &[Partial],
+) -> Result<(), String>
+where
+    Machines: MachineEnvironment,
+    Deadline: Default,
+    ReleaseTime: Default,
+    Weight: Default,
+    Partial: ScheduleEntry,
+{
+    if let Some(p) = partials
+        .windows(2)
+        .find(|p| p[0].start_time() > p[1].start_time())
+    {
+        return Err(format!(
+            "Job {} starting at {} is emitted after job {} starting at {}.",
+            p[0].job(),
+            p[0].start_time(),
+            p[1].job(),
+            p[1].start_time()
+        ));
+    }
+
+    let operation_machines = instance.environment.operation_machines();
+    let jobs: HashMap<u32, &Vec<i32>> = instance
+        .jobs
+        .iter()
+        .map(|j| (j.id, &j.operations))
+        .collect();
+    let mut scheduled: HashSet<(u32, u32)> = HashSet::with_capacity(partials.len());
+    let mut machine_intervals: HashMap<u32, Vec<(i64, i64)>> = HashMap::new();
+    let mut job_intervals: HashMap<u32, Vec<(i64, i64)>> = HashMap::new();
+
+    for p in partials {
+        let (job, machine) = (p.job(), p.machine());
+        let operations = jobs
+            .get(&job)
+            .ok_or_else(|| format!("Job {} is not part of the instance.", job))?;
+        if !(1..=instance.environment.machines()).contains(&machine) {
+            return Err(format!(
+                "Job {} is scheduled on machine {}, which doesn't exist.",
+                job, machine
+            ));
+        }
+        let processing_time = operations
+            .get(operation_index(operation_machines, machine))
+            .ok_or_else(|| format!("Job {} can't be processed on machine {}.", job, machine))?;
+        if !scheduled.insert((job, machine)) {
+            return Err(format!(
+                "Job {} is scheduled twice on machine {}.",
+                job, machine
+            ));
+        }
+
+        let interval = (p.start_time(), p.start_time() + i64::from(*processing_time));
+        machine_intervals.entry(machine).or_default().push(interval);
+        job_intervals.entry(job).or_default().push(interval);
+    }
+
+    for j in &instance.jobs {
+        let times_scheduled = job_intervals.get(&j.id).map_or(0, Vec::len);
+        let expected = if operation_machines == OperationMachines::EveryMachine {
+            j.operations.len()
+        } else {
+            1
+        };
+        if times_scheduled != expected {
+            return Err(format!(
+                "Job {} is scheduled {} times instead of {}.",
+                j.id, times_scheduled, expected
+            ));
+        }
+    }
+
+    for (machine, intervals) in machine_intervals.iter_mut() {
+        if let Some(start) = find_overlap(intervals) {
+            return Err(format!("Jobs overlap on machine {} at {}.", machine, start));
+        }
+    }
+    for (job, intervals) in job_intervals.iter_mut() {
+        if let Some(start) = find_overlap(intervals) {
+            return Err(format!(
+                "Job {} is processed on two machines at {}.",
+                job, start
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+/// Returns the makespan of the schedule, i.e. the latest completion time of any operation.
+///
+/// The processing times are determined by [`MachineEnvironment::operation_machines`], just like in [`validate_schedule`].
+///
+/// # Panics
+///
+/// Panics if the schedule contains a job that is not part of the instance or can't be processed on its machine.
+pub fn makespan<Machines, Deadline, ReleaseTime, Precedences, Weight, Partial>(
+    instance: &SchedulingInstance<Machines, i32, Deadline, ReleaseTime, Precedences, Weight>,
+    schedule: &[Partial],
+) -> i64
+where
+    Machines: MachineEnvironment,
+    Deadline: Default,
+    ReleaseTime: Default,
+    Weight: Default,
+    Partial: ScheduleEntry,
+{
+    let operation_machines = instance.environment.operation_machines();
+    let jobs: HashMap<u32, &Vec<i32>> = instance
+        .jobs
+        .iter()
+        .map(|j| (j.id, &j.operations))
+        .collect();
+    schedule
+        .iter()
+        .map(|p| {
+            let operation = operation_index(operation_machines, p.machine());
+            p.start_time() + i64::from(jobs[&p.job()][operation])
+        })
+        .max()
+        .unwrap_or(0)
+}
+
+/// Returns the index of the operation that is processed on the given machine, numbered from 1.
+fn operation_index(operation_machines: OperationMachines, machine: u32) -> usize {
+    match operation_machines {
+        OperationMachines::AnyMachine => 0,
+        _ => (machine - 1) as usize,
+    }
+}
+
+/// Returns the start of an interval that overlaps with a previous one, if any.
+fn find_overlap(intervals: &mut [(i64, i64)]) -> Option<i64> {
+    intervals.sort_unstable();
+    intervals
+        .windows(2)
+        .find(|i| i[1].0 < i[0].1)
+        .map(|i| i[1].0)
+}
+
+#[cfg(test)]
+mod test {
+    use crate::data_structures::scheduling_problems::{
+        FlowShop, Job, ParallelMachines, SingleMachine,
+    };
+
+    use super::*;
+
+    fn flow_shop() -> SchedulingInstance<FlowShop, i32> {
+        SchedulingInstance {
+            environment: FlowShop { machines: 2 },
+            jobs: vec![
+                Job::new(1, vec![2, 3], (), ()),
+                Job::new(2, vec![4, 1], (), ()),
+            ],
+            precedences: (),
+        }
+    }
+
+    fn flow_shop_schedule(partials: &[(u32, u32, i64)]) -> Vec<flow_shop::SchedulePartial> {
+        partials
+            .iter()
+            .map(|&(job, machine, time)| flow_shop::SchedulePartial { job, machine, time })
+            .collect()
+    }
+
+    #[test]
+    fn test_valid_schedule() {
+        let schedule = flow_shop_schedule(&[(1, 1, 0), (2, 1, 2), (1, 2, 2), (2, 2, 6)]);
+        assert_eq!(validate_schedule(&flow_shop(), &schedule), Ok(()));
+
+        let instance = SchedulingInstance {
+            environment: SingleMachine,
+            jobs: vec![Job::new(0, vec![3], (), ()), Job::new(1, vec![2], (), ())],
+            precedences: (),
+        };
+        let schedule = [
+            single_machine::SchedulePartial { job: 1, time: 0 },
+            single_machine::SchedulePartial { job: 0, time: 5 },
+        ];
+        assert_eq!(validate_schedule(&instance, &schedule), Ok(()));
+    }
+
+    #[test]
+    fn test_unsorted_emission() {
+        let schedule = flow_shop_schedule(&[(1, 1, 0), (1, 2, 2), (2, 1, 2), (2, 2, 6)]);
+        assert!(validate_schedule(&flow_shop(), &schedule).is_ok());
+
+        let schedule = flow_shop_schedule(&[(1, 1, 0), (2, 2, 6), (2, 1, 2), (1, 2, 2)]);
+        assert!(validate_schedule(&flow_shop(), &schedule).is_err());
+    }
+
+    #[test]
+    fn test_missing_and_duplicate_jobs() {
+        let missing = flow_shop_schedule(&[(1, 1, 0), (2, 1, 2), (1, 2, 2)]);
+        assert!(validate_schedule(&flow_shop(), &missing).is_err());
+
+        let duplicate = flow_shop_schedule(&[(1, 1, 0), (2, 1, 2), (1, 2, 2), (1, 2, 6)]);
+        assert!(validate_schedule(&flow_shop(), &duplicate).is_err());
+
+        let unknown = flow_shop_schedule(&[(1, 1, 0), (2, 1, 2), (1, 2, 2), (3, 2, 6)]);
+        assert!(validate_schedule(&flow_shop(), &unknown).is_err());
+
+        let wrong_machine = flow_shop_schedule(&[(1, 1, 0), (2, 1, 2), (1, 3, 2), (2, 2, 6)]);
+        assert!(validate_schedule(&flow_shop(), &wrong_machine).is_err());
+
+        let no_machine = flow_shop_schedule(&[(1, 0, 0), (2, 1, 2), (1, 2, 2), (2, 2, 6)]);
+        assert!(validate_schedule(&flow_shop(), &no_machine).is_err());
+    }
+
+    #[test]
+    fn test_machine_out_of_range() {
+        let instance = SchedulingInstance {
+            environment: ParallelMachines { machines: 2 },
+            jobs: vec![Job::new(0, vec![3], (), ()), Job::new(1, vec![2], (), ())],
+            precedences: (),
+        };
+
+        let valid = flow_shop_schedule(&[(0, 1, 0), (1, 2, 0)]);
+        assert!(validate_schedule(&instance, &valid).is_ok());
+
+        let beyond = flow_shop_schedule(&[(0, 1, 0), (1, 3, 0)]);
+        assert!(validate_schedule(&instance, &beyond).is_err());
+
+        let zero = flow_shop_schedule(&[(0, 0, 0), (1, 1, 0)]);
+        assert!(validate_schedule(&instance, &zero).is_err());
+    }
+
+    #[test]
+    fn test_makespan() {
+        let instance = SchedulingInstance {
+            environment: FlowShop { machines: 2 },
+            jobs: vec![
+                Job::new(7, vec![3, 2], (), ()),
+                Job::new(4, vec![1, 5], (), ()),
+            ],
+            precedences: (),
+        };
+        let schedule = flow_shop_schedule(&[(4, 1, 0), (7, 1, 1), (4, 2, 1), (7, 2, 6)]);
+        assert_eq!(makespan(&instance, &schedule), 8);
+        assert_eq!(makespan(&instance, &schedule[..0]), 0);
+
+        // identical machines always use the first operation
+        let instance = SchedulingInstance {
+            environment: ParallelMachines { machines: 2 },
+            jobs: vec![Job::new(0, vec![3], (), ()), Job::new(1, vec![2], (), ())],
+            precedences: (),
+        };
+        let schedule = flow_shop_schedule(&[(0, 1, 0), (1, 2, 0)]);
+        assert_eq!(makespan(&instance, &schedule), 3);
+    }
+
+    #[test]
+    fn test_overlaps() {
+        // jobs 1 and 2 overlap on machine 1
+        let schedule = flow_shop_schedule(&[(1, 1, 0), (2, 1, 1), (1, 2, 2), (2, 2, 6)]);
+        assert!(validate_schedule(&flow_shop(), &schedule).is_err());
+
+        // job 1 is processed on both machines at time 1
+        let schedule = flow_shop_schedule(&[(1, 1, 0), (1, 2, 1), (2, 1, 2), (2, 2, 6)]);
+        assert!(validate_schedule(&flow_shop(), &schedule).is_err());
+    }
+}
@@ -1,8 +1,124 @@
 pub mod f2_cmax;
+pub mod f3_cmax;
 
-#[derive(Debug, Clone, Copy, PartialEq)]
+use std::{cmp::Ordering, collections::HashSet};
+
+use super::ScheduleEntry;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct SchedulePartial {
     pub job: u32,
     pub machine: u32,
     pub time: i64,
 }
+
+/// Partials are ordered by time, then machine, then job.
+impl Ord for SchedulePartial {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (self.time, self.machine, self.job).cmp(&(other.time, other.machine, other.job))
+    }
+}
+
+impl PartialOrd for SchedulePartial {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl ScheduleEntry for SchedulePartial {
+    fn job(&self) -> u32 {
+        self.job
+    }
+
+    fn machine(&self) -> u32 {
+        self.machine
+    }
+
+    fn start_time(&self) -> i64 {
+        self.time
+    }
+}
+
+/// Returns the candidate partial with the earliest start time, breaking ties by the smaller machine id.
+///
+/// Candidates that are `None` are skipped; returns `None` if there is no candidate left.
+pub fn earliest_partial(
+    candidates: impl Iterator<Item = Option<SchedulePartial>>,
+) -> Option<SchedulePartial> {
+    candidates.flatten().min()
+}
+
+/// Returns the number of distinct machines that the schedule uses.
+pub fn machines_used(schedule: &[SchedulePartial]) -> u32 {
+    let machines: HashSet<u32> = schedule.iter().map(|p| p.machine).collect();
+    machines.len() as u32
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn partial(job: u32, machine: u32, time: i64) -> SchedulePartial {
+        SchedulePartial { job, machine, time }
+    }
+
+    #[test]
+    fn test_ordering() {
+        assert!(partial(9, 9, 1) < partial(1, 1, 2));
+        assert!(partial(9, 1, 2) < partial(1, 2, 2));
+        assert!(partial(1, 2, 2) < partial(2, 2, 2));
+        assert_eq!(partial(3, 2, 1).cmp(&partial(3, 2, 1)), Ordering::Equal);
+    }
+
+    #[test]
+    fn test_earliest_partial_empty() {
+        assert_eq!(earliest_partial(std::iter::empty()), None);
+        assert_eq!(earliest_partial([None, None, None].into_iter()), None);
+    }
+
+    #[test]
+    fn test_earliest_partial_single() {
+        let p = partial(4, 2, 7);
+        assert_eq!(earliest_partial([None, Some(p), None].into_iter()), Some(p));
+    }
+
+    #[test]
+    fn test_earliest_partial_earliest_time() {
+        let candidates = [Some(partial(1, 1, 9)), Some(partial(2, 3, 4)), None];
+        assert_eq!(
+            earliest_partial(candidates.into_iter()),
+            Some(partial(2, 3, 4))
+        );
+    }
+
+    #[test]
+    fn test_earliest_partial_tie() {
+        let candidates = [
+            Some(partial(1, 3, 5)),
+            None,
+            Some(partial(2, 1, 5)),
+            Some(partial(3, 2, 5)),
+        ];
+        assert_eq!(
+            earliest_partial(candidates.into_iter()),
+            Some(partial(2, 1, 5))
+        );
+    }
+
+    #[test]
+    fn test_machines_used() {
+        let schedule = [
+            partial(1, 1, 0),
+            partial(1, 2, 3),
+            partial(2, 1, 3),
+            partial(1, 3, 5),
+            partial(2, 2, 5),
+        ];
+        assert_eq!(machines_used(&schedule), 3);
+
+        let schedule = [partial(1, 2, 0), partial(2, 2, 4), partial(3, 2, 6)];
+        assert_eq!(machines_used(&schedule), 1);
+
+        assert_eq!(machines_used(&[]), 0);
+    }
+}
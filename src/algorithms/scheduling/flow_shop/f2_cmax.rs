@@ -16,7 +16,7 @@ use crate::{
     experiments::{ExperimentAlgorithm, PreparedEnumerationAlgorithm},
 };
 
-use super::SchedulePartial;
+use super::{earliest_partial, SchedulePartial};
 
 pub type AlgorithmType =
     ExperimentAlgorithm<SchedulingInstance<FlowShop, i32>, SchedulePartial, Vec<SchedulePartial>>;
@@ -66,7 +66,7 @@ impl<'a> EnumerateWithIQS<'a> {
 
     fn algorithm(
         input: &SchedulingInstance<FlowShop, i32>,
-    ) -> PreparedEnumerationAlgorithm<SchedulePartial> {
+    ) -> PreparedEnumerationAlgorithm<'_, SchedulePartial> {
         Box::new(EnumerateWithIQS::new(input))
     }
 }
@@ -99,7 +99,9 @@ impl Iterator for EnumerateWithIQS<'_> {
         }
 
         // emit the next earliest schedule entry
-        match min_time(self.next_machine_1, self.queue_machine_2.front().copied()) {
+        match earliest_partial(
+            [self.next_machine_1, self.queue_machine_2.front().copied()].into_iter(),
+        ) {
             Some(SchedulePartial { machine: 1, .. }) => self.next_machine_1.take(),
             Some(SchedulePartial { machine: 2, .. }) => self.queue_machine_2.pop_front(),
             None => None,
@@ -108,13 +110,6 @@ impl Iterator for EnumerateWithIQS<'_> {
     }
 }
 
-fn min_time(sp1: Option<SchedulePartial>, sp2: Option<SchedulePartial>) -> Option<SchedulePartial> {
-    // if one is None, take the other (as result of x = min(x,x))
-    // if both are None, returns None
-    // if both are Some, compares them by time and returns the partial with smaller time
-    std::cmp::min_by_key(sp1.or(sp2), sp2.or(sp1), |sp| sp.map(|p| p.time))
-}
-
 /// Total time algorithm for F2||C_max with rust's sort_unstable_by_key
 pub const SOLVE_WITH_UNSTABLE_SORT: AlgorithmType =
     ExperimentAlgorithm::TotalTimeAlgorithm("total-unstable-sort", rust_unstable_sort);
@@ -167,6 +162,11 @@ fn rust_unstable_sort(input: &SchedulingInstance<FlowShop, i32>) -> Vec<Schedule
 
 #[cfg(test)]
 mod test {
+    use crate::{
+        algorithms::scheduling::{makespan, validate_schedule},
+        experiments::assert_enum_matches_total,
+    };
+
     use super::*;
 
     // this instance is taken from Johnson's paper (see [1] above)
@@ -195,16 +195,12 @@ mod test {
             environment: FlowShop { machines: 2 },
             jobs: JOHNSON_INSTANCE
                 .iter()
-                .map(|j| Job {
-                    id: j.0,
-                    operations: vec![j.1, j.2],
-                    deadline: (),
-                    release_time: (),
-                })
+                .map(|j| Job::new(j.0, vec![j.1, j.2], (), ()))
                 .collect(),
             precedences: (),
         };
         let mut schedule: Vec<_> = EnumerateWithIQS::new(&instance).collect();
+        assert_eq!(validate_schedule(&instance, &schedule), Ok(()));
 
         assert!(
             schedule
@@ -214,8 +210,7 @@ mod test {
             "Partials not sorted by time."
         );
 
-        schedule.sort_by_key(|p| p.machine);
-        schedule.sort_by_key(|p| p.time);
+        schedule.sort();
 
         assert_eq!(
             schedule,
@@ -233,16 +228,12 @@ mod test {
             environment: FlowShop { machines: 2 },
             jobs: JOHNSON_INSTANCE
                 .iter()
-                .map(|j| Job {
-                    id: j.0,
-                    operations: vec![j.1, j.2],
-                    deadline: (),
-                    release_time: (),
-                })
+                .map(|j| Job::new(j.0, vec![j.1, j.2], (), ()))
                 .collect(),
             precedences: (),
         };
         let mut schedule = rust_unstable_sort(&instance);
+        assert_eq!(validate_schedule(&instance, &schedule), Ok(()));
 
         assert!(
             schedule
@@ -252,8 +243,7 @@ mod test {
             "Partials not sorted by time."
         );
 
-        schedule.sort_by_key(|p| p.machine);
-        schedule.sort_by_key(|p| p.time);
+        schedule.sort();
 
         assert_eq!(
             schedule,
@@ -264,4 +254,22 @@ mod test {
             })
         );
     }
+
+    #[test]
+    fn test_f2_cmax_enumeration_matches_total_time() {
+        let instance = SchedulingInstance {
+            environment: FlowShop { machines: 2 },
+            jobs: JOHNSON_INSTANCE
+                .iter()
+                .map(|j| Job::new(j.0, vec![j.1, j.2], (), ()))
+                .collect(),
+            precedences: (),
+        };
+        assert_enum_matches_total(
+            &instance,
+            &ENUMERATE_WITH_IQS,
+            &SOLVE_WITH_UNSTABLE_SORT,
+            |schedule: &Vec<SchedulePartial>| makespan(&instance, schedule),
+        );
+    }
 }
@@ -0,0 +1,288 @@
+//! Heuristic algorithms for F3||C_max
+//!
+//! F3||C_max is NP-hard, but Johnson \[1\] showed that his rule for two machines can be extended:
+//! the three-machine instance is reduced to a two-machine instance with the processing times
+//! `a_j = p_1j + p_2j` and `b_j = p_2j + p_3j`, whose Johnson order is used as the same job order on all
+//! three machines. The resulting schedule is optimal if the second machine is dominated,
+//! i.e. if `min_j p_1j >= max_j p_2j` or `min_j p_3j >= max_j p_2j`, and a heuristic otherwise.
+//!
+//! \[1\] S. M. Johnson, “Optimal two- and three-stage production schedules with setup times included,” Naval Research Logistics Quarterly, vol. 1, no. 1, pp. 61–68, 1954, doi: [10.1002/nav.3800010110](https://doi.org/10.1002/nav.3800010110).
+
+use std::{cmp::Reverse, collections::VecDeque};
+
+use crate::{
+    algorithms::sorting::IQS,
+    data_structures::scheduling_problems::{FlowShop, Job, SchedulingInstance},
+    experiments::{ExperimentAlgorithm, PreparedEnumerationAlgorithm},
+};
+
+use super::{earliest_partial, SchedulePartial};
+
+pub type AlgorithmType =
+    ExperimentAlgorithm<SchedulingInstance<FlowShop, i32>, SchedulePartial, Vec<SchedulePartial>>;
+
+/// Processing time of the job on the first machine of the reduced two-machine instance
+fn reduced_time_1(j: &Job<i32>) -> i32 {
+    j.operations[0] + j.operations[1]
+}
+
+/// Processing time of the job on the second machine of the reduced two-machine instance
+fn reduced_time_2(j: &Job<i32>) -> i32 {
+    j.operations[1] + j.operations[2]
+}
+
+fn assert_three_machines(input: &SchedulingInstance<FlowShop, i32>) {
+    assert_eq!(
+        input.environment.machines, 3,
+        "Johnson's extension only works for exactly 3 machines."
+    );
+}
+
+/// Enumeration algorithm for F3||C_max with IQS for incremental sorting
+pub const ENUMERATE_WITH_IQS: AlgorithmType =
+    ExperimentAlgorithm::EnumerationAlgorithm("enum-iqs", EnumerateWithIQS::algorithm);
+
+struct EnumerateWithIQS<'a> {
+    iqs: std::iter::Chain<IQS<&'a Job<i32>>, IQS<&'a Job<i32>>>,
+    time_machine_1: i64,
+    next_machine_1: Option<SchedulePartial>,
+    time_machine_2: i64,
+    queue_machine_2: VecDeque<SchedulePartial>,
+    time_machine_3: i64,
+    queue_machine_3: VecDeque<SchedulePartial>,
+}
+
+impl<'a> EnumerateWithIQS<'a> {
+    pub fn new(input: &'a SchedulingInstance<FlowShop, i32>) -> Self {
+        assert_three_machines(input);
+        let jobs_faster_or_equal_on_machine_1: Vec<&Job<i32>> = input
+            .jobs
+            .iter()
+            .filter(|j| reduced_time_1(j) <= reduced_time_2(j))
+            .collect();
+        let jobs_faster_on_machine_2: Vec<&Job<i32>> = input
+            .jobs
+            .iter()
+            .filter(|j| reduced_time_1(j) > reduced_time_2(j))
+            .collect();
+        let iqs = IQS::with_comparator(&jobs_faster_or_equal_on_machine_1, |j1, j2| {
+            reduced_time_1(j1).cmp(&reduced_time_1(j2))
+        })
+        .chain(IQS::with_comparator(&jobs_faster_on_machine_2, |j1, j2| {
+            reduced_time_2(j1).cmp(&reduced_time_2(j2)).reverse()
+        }));
+        Self {
+            iqs,
+            time_machine_1: 0,
+            next_machine_1: None,
+            time_machine_2: 0,
+            queue_machine_2: VecDeque::new(),
+            time_machine_3: 0,
+            queue_machine_3: VecDeque::new(),
+        }
+    }
+
+    fn algorithm(
+        input: &SchedulingInstance<FlowShop, i32>,
+    ) -> PreparedEnumerationAlgorithm<'_, SchedulePartial> {
+        Box::new(EnumerateWithIQS::new(input))
+    }
+}
+
+impl Iterator for EnumerateWithIQS<'_> {
+    type Item = SchedulePartial;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.next_machine_1.is_none() {
+            if let Some(j) = self.iqs.next() {
+                let [p1, p2, p3] = [0, 1, 2].map(|i| i64::from(j.operations[i]));
+                // start on machine 1 as soon as the machine is free
+                let start_time_m1 = self.time_machine_1;
+                self.time_machine_1 += p1;
+                // start as soon as the machine is free and the job is done on the previous machine
+                let start_time_m2 = self.time_machine_2.max(self.time_machine_1);
+                self.time_machine_2 = start_time_m2 + p2;
+                let start_time_m3 = self.time_machine_3.max(self.time_machine_2);
+                self.time_machine_3 = start_time_m3 + p3;
+
+                self.next_machine_1 = Some(SchedulePartial {
+                    job: j.id,
+                    machine: 1,
+                    time: start_time_m1,
+                });
+                self.queue_machine_2.push_back(SchedulePartial {
+                    job: j.id,
+                    machine: 2,
+                    time: start_time_m2,
+                });
+                self.queue_machine_3.push_back(SchedulePartial {
+                    job: j.id,
+                    machine: 3,
+                    time: start_time_m3,
+                });
+            };
+        }
+
+        // emit the next earliest schedule entry
+        match earliest_partial(
+            [
+                self.next_machine_1,
+                self.queue_machine_2.front().copied(),
+                self.queue_machine_3.front().copied(),
+            ]
+            .into_iter(),
+        ) {
+            Some(SchedulePartial { machine: 1, .. }) => self.next_machine_1.take(),
+            Some(SchedulePartial { machine: 2, .. }) => self.queue_machine_2.pop_front(),
+            Some(SchedulePartial { machine: 3, .. }) => self.queue_machine_3.pop_front(),
+            None => None,
+            _ => panic!("F3||C_max can't schedule on machines other than 1, 2 or 3."),
+        }
+    }
+}
+
+/// Total time algorithm for F3||C_max with rust's sort_unstable_by_key
+pub const SOLVE_WITH_UNSTABLE_SORT: AlgorithmType =
+    ExperimentAlgorithm::TotalTimeAlgorithm("total-unstable-sort", rust_unstable_sort);
+
+fn rust_unstable_sort(input: &SchedulingInstance<FlowShop, i32>) -> Vec<SchedulePartial> {
+    assert_three_machines(input);
+
+    let mut jobs_faster_or_equal_on_machine_1: Vec<&Job<i32>> = input
+        .jobs
+        .iter()
+        .filter(|j| reduced_time_1(j) <= reduced_time_2(j))
+        .collect();
+    jobs_faster_or_equal_on_machine_1.sort_unstable_by_key(|j| reduced_time_1(j));
+    let mut jobs_faster_on_machine_2: Vec<&Job<i32>> = input
+        .jobs
+        .iter()
+        .filter(|j| reduced_time_1(j) > reduced_time_2(j))
+        .collect();
+    jobs_faster_on_machine_2.sort_unstable_by_key(|j| Reverse(reduced_time_2(j)));
+
+    let mut schedule = Vec::with_capacity(3 * input.jobs.len());
+    let mut machine_times = [0; 3];
+    for j in jobs_faster_or_equal_on_machine_1
+        .iter()
+        .chain(jobs_faster_on_machine_2.iter())
+    {
+        // the job is done on the previous machine; machine 1 has no predecessor
+        let mut previous_completion = 0;
+        for (i, machine_time) in machine_times.iter_mut().enumerate() {
+            let start_time = (*machine_time).max(previous_completion);
+            *machine_time = start_time + i64::from(j.operations[i]);
+            previous_completion = *machine_time;
+            schedule.push(SchedulePartial {
+                job: j.id,
+                machine: i as u32 + 1,
+                time: start_time,
+            });
+        }
+    }
+    schedule.sort_by_key(|p| p.time);
+    schedule
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        algorithms::scheduling::{makespan, validate_schedule},
+        experiments::assert_enum_matches_total,
+    };
+
+    use super::*;
+
+    // The second machine is dominated by the first, so Johnson's extension is optimal.
+    // First entry is the job id, then come the processing times on the three machines.
+    const INSTANCE: [(u32, i32, i32, i32); 3] = [(1, 5, 2, 4), (2, 6, 1, 7), (3, 4, 3, 2)];
+
+    // Job order 2, 1, 3 with makespan 20, which matches the lower bound
+    // sum_j p_1j + min_j (p_2j + p_3j) = 15 + 5.
+    // First entry is the job id, then comes the machine and third the start time.
+    const SOLUTION: [(u32, u32, i64); 9] = [
+        (2, 1, 0),
+        (1, 1, 6),
+        (2, 2, 6),
+        (2, 3, 7),
+        (3, 1, 11),
+        (1, 2, 11),
+        (1, 3, 14),
+        (3, 2, 15),
+        (3, 3, 18),
+    ];
+
+    fn instance() -> SchedulingInstance<FlowShop, i32> {
+        SchedulingInstance {
+            environment: FlowShop { machines: 3 },
+            jobs: INSTANCE
+                .iter()
+                .map(|j| Job::new(j.0, vec![j.1, j.2, j.3], (), ()))
+                .collect(),
+            precedences: (),
+        }
+    }
+
+    fn assert_solution(mut schedule: Vec<SchedulePartial>) {
+        assert!(
+            schedule
+                .as_slice()
+                .windows(2)
+                .all(|p| p[0].time <= p[1].time),
+            "Partials not sorted by time."
+        );
+
+        schedule.sort();
+
+        assert_eq!(
+            schedule,
+            SOLUTION.map(|s| SchedulePartial {
+                job: s.0,
+                machine: s.1,
+                time: s.2,
+            })
+        );
+    }
+
+    #[test]
+    fn test_f3_cmax_enumeration() {
+        let instance = instance();
+        let schedule: Vec<_> = EnumerateWithIQS::new(&instance).collect();
+        assert_eq!(validate_schedule(&instance, &schedule), Ok(()));
+
+        assert_eq!(makespan(&instance, &schedule), 20);
+        assert_solution(schedule);
+    }
+
+    #[test]
+    fn test_f3_cmax_total_time() {
+        let instance = instance();
+        let schedule = rust_unstable_sort(&instance);
+        assert_eq!(validate_schedule(&instance, &schedule), Ok(()));
+
+        assert_eq!(makespan(&instance, &schedule), 20);
+        assert_solution(schedule);
+    }
+
+    #[test]
+    fn test_f3_cmax_enumeration_matches_total_time() {
+        let instance = instance();
+        assert_enum_matches_total(
+            &instance,
+            &ENUMERATE_WITH_IQS,
+            &SOLVE_WITH_UNSTABLE_SORT,
+            |schedule: &Vec<SchedulePartial>| makespan(&instance, schedule),
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_f3_cmax_wrong_number_of_machines() {
+        let instance = SchedulingInstance {
+            environment: FlowShop { machines: 2 },
+            jobs: vec![Job::new(1, vec![1, 2], (), ())],
+            precedences: (),
+        };
+        rust_unstable_sort(&instance);
+    }
+}
@@ -0,0 +1,5 @@
+pub mod p_cmax;
+pub mod r_cmax;
+
+/// A job together with its machine and start time, as in flow shops.
+pub use super::flow_shop::SchedulePartial;
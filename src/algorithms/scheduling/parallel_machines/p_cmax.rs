@@ -0,0 +1,191 @@
+//! Approximation algorithms for P||C_max
+//!
+//! Longest processing time first (LPT) \[1\] assigns the jobs in order of non-increasing processing times,
+//! each to the machine with the currently smallest load. Its makespan is at most `4/3 - 1/(3m)` times the optimum.
+//!
+//! The local search improves the LPT schedule by repeatedly moving a job from a most-loaded to a least-loaded
+//! machine, or swapping a job of the most-loaded machine with a shorter job of the least-loaded machine,
+//! as long as the load of the most-loaded machine strictly decreases and the other machine doesn't reach it.
+//! Note that LPT schedules can't be improved by moves alone: every job on a most-loaded machine is
+//! at least as long as the job that was assigned to it last, at a time when the least-loaded machine
+//! already had at least that machine's load without this job.
+//!
+//! Machines are numbered from 1 to m.
+//!
+//! \[1\] R. L. Graham, “Bounds on Multiprocessing Timing Anomalies,” SIAM Journal on Applied Mathematics, vol. 17, no. 2, pp. 416–429, 1969, doi: [10.1137/0117039](https://doi.org/10.1137/0117039).
+
+use std::{cmp::Reverse, collections::BinaryHeap};
+
+use crate::{
+    data_structures::scheduling_problems::{Job, ParallelMachines, SchedulingInstance},
+    experiments::ExperimentAlgorithm,
+};
+
+use super::SchedulePartial;
+
+type InstanceType = SchedulingInstance<ParallelMachines, i32>;
+pub type AlgorithmType = ExperimentAlgorithm<InstanceType, SchedulePartial, Vec<SchedulePartial>>;
+
+/// Total time algorithm for P||C_max with LPT
+pub const APPROXIMATE_WITH_LPT: AlgorithmType =
+    ExperimentAlgorithm::TotalTimeAlgorithm("total-lpt", lpt);
+
+fn lpt(input: &InstanceType) -> Vec<SchedulePartial> {
+    to_schedule(&lpt_assignment(input))
+}
+
+/// Total time algorithm for P||C_max with LPT and a subsequent local search
+pub const APPROXIMATE_WITH_LPT_LOCALSEARCH: AlgorithmType =
+    ExperimentAlgorithm::TotalTimeAlgorithm("total-lpt-local-search", lpt_local_search);
+
+fn lpt_local_search(input: &InstanceType) -> Vec<SchedulePartial> {
+    let mut assignment = lpt_assignment(input);
+    improve_locally(&mut assignment);
+    to_schedule(&assignment)
+}
+
+/// The jobs assigned to each machine, in processing order
+type Assignment<'a> = Vec<Vec<&'a Job<i32>>>;
+
+fn lpt_assignment(input: &InstanceType) -> Assignment<'_> {
+    let machines = input.environment.machines as usize;
+    assert!(machines > 0, "There must be at least one machine.");
+
+    let mut sorted_jobs: Vec<&Job<i32>> = input.jobs.iter().collect();
+    sorted_jobs.sort_unstable_by_key(|j| (Reverse(j.operations[0]), j.id));
+
+    let mut assignment: Assignment = vec![Vec::new(); machines];
+    // least loaded machine first, ties broken by the smaller machine
+    let mut loads: BinaryHeap<Reverse<(i64, usize)>> =
+        (0..machines).map(|m| Reverse((0, m))).collect();
+    for j in sorted_jobs {
+        let Reverse((load, machine)) = loads.pop().expect("there is at least one machine");
+        assignment[machine].push(j);
+        loads.push(Reverse((load + i64::from(j.operations[0]), machine)));
+    }
+    assignment
+}
+
+fn load(jobs: &[&Job<i32>]) -> i64 {
+    jobs.iter().map(|j| i64::from(j.operations[0])).sum()
+}
+
+/// Moves and swaps jobs between a most- and a least-loaded machine until that no longer helps.
+///
+/// Each step strictly decreases the sum of squared loads, so the search terminates.
+fn improve_locally(assignment: &mut Assignment) {
+    loop {
+        let loads: Vec<i64> = assignment.iter().map(|jobs| load(jobs)).collect();
+        let max = (0..loads.len())
+            .max_by_key(|&m| (loads[m], Reverse(m)))
+            .expect("there is at least one machine");
+        let min = (0..loads.len())
+            .min_by_key(|&m| (loads[m], m))
+            .expect("there is at least one machine");
+
+        // best exchange of job a on the max machine with job b (or nothing) on the min machine
+        let mut best: Option<(i64, usize, Option<usize>)> = None;
+        for (a, job_a) in assignment[max].iter().enumerate() {
+            let partners = assignment[min].iter().map(Some).chain([None]).enumerate();
+            for (b, job_b) in partners {
+                let difference = i64::from(job_a.operations[0])
+                    - job_b.map_or(0, |j| i64::from(j.operations[0]));
+                if difference <= 0 || loads[min] + difference >= loads[max] {
+                    continue;
+                }
+                let new_max = (loads[max] - difference).max(loads[min] + difference);
+                if best.is_none_or(|(best_max, ..)| new_max < best_max) {
+                    best = Some((new_max, a, job_b.map(|_| b)));
+                }
+            }
+        }
+
+        let Some((_, a, b)) = best else {
+            return;
+        };
+        let job_a = assignment[max].remove(a);
+        if let Some(b) = b {
+            let job_b = assignment[min].remove(b);
+            assignment[max].push(job_b);
+        }
+        assignment[min].push(job_a);
+    }
+}
+
+/// Returns the partials of the assignment without idle time, sorted by start time.
+fn to_schedule(assignment: &Assignment) -> Vec<SchedulePartial> {
+    let mut schedule = Vec::with_capacity(assignment.iter().map(Vec::len).sum());
+    for (machine, jobs) in assignment.iter().enumerate() {
+        let mut time = 0;
+        for j in jobs {
+            schedule.push(SchedulePartial {
+                job: j.id,
+                machine: machine as u32 + 1,
+                time,
+            });
+            time += i64::from(j.operations[0]);
+        }
+    }
+    schedule.sort();
+    schedule
+}
+
+#[cfg(test)]
+mod test {
+    use crate::algorithms::scheduling::{makespan, validate_schedule};
+
+    use super::*;
+
+    fn instance(machines: u32, processing_times: &[i32]) -> InstanceType {
+        SchedulingInstance {
+            environment: ParallelMachines { machines },
+            jobs: processing_times
+                .iter()
+                .zip(0..)
+                .map(|(&p, id)| Job::new(id, vec![p], (), ()))
+                .collect(),
+            precedences: (),
+        }
+    }
+
+    // Graham's worst case for LPT on m = 3 machines: 2m + 1 jobs with an optimal makespan of 3m,
+    // for which LPT has makespan 4m - 1.
+    const WORST_CASE: [i32; 7] = [5, 5, 4, 4, 3, 3, 3];
+
+    #[test]
+    fn test_lpt_worst_case() {
+        let instance = instance(3, &WORST_CASE);
+        let schedule = lpt(&instance);
+
+        assert_eq!(validate_schedule(&instance, &schedule), Ok(()));
+        assert_eq!(makespan(&instance, &schedule), 11);
+    }
+
+    #[test]
+    fn test_local_search_worst_case() {
+        let instance = instance(3, &WORST_CASE);
+        let schedule = lpt_local_search(&instance);
+
+        assert_eq!(validate_schedule(&instance, &schedule), Ok(()));
+        // swapping 5 <-> 3 and then 5 <-> 4 reaches the optimum {3, 3, 3}, {5, 4}, {5, 4}
+        assert_eq!(makespan(&instance, &schedule), 9);
+    }
+
+    #[test]
+    fn test_local_search_never_worse() {
+        for (machines, processing_times) in [
+            (2, &[3, 3, 2, 2, 2][..]),
+            (3, &WORST_CASE[..]),
+            (4, &[7, 1, 8, 2, 8, 3, 9, 4, 5, 6, 1, 1][..]),
+            (3, &[10, 1][..]),
+            (2, &[][..]),
+        ] {
+            let instance = instance(machines, processing_times);
+            let lpt_makespan = makespan(&instance, &lpt(&instance));
+            let schedule = lpt_local_search(&instance);
+
+            assert_eq!(validate_schedule(&instance, &schedule), Ok(()));
+            assert!(makespan(&instance, &schedule) <= lpt_makespan);
+        }
+    }
+}
@@ -0,0 +1,159 @@
+//! Approximation algorithms for R||C_max
+//!
+//! Greedy list scheduling: the jobs are assigned in the given order, each to the machine
+//! on which it would be completed first. Ties are broken by the smaller machine id.
+//! Machines are numbered from 1 to m.
+
+use crate::{
+    data_structures::scheduling_problems::{SchedulingInstance, UnrelatedMachines},
+    experiments::{ExperimentAlgorithm, PreparedEnumerationAlgorithm},
+};
+
+use super::SchedulePartial;
+
+type InstanceType = SchedulingInstance<UnrelatedMachines, i32>;
+pub type AlgorithmType = ExperimentAlgorithm<InstanceType, SchedulePartial, Vec<SchedulePartial>>;
+
+/// Enumeration algorithm for R||C_max with greedy list scheduling
+pub const ENUMERATE_GREEDY: AlgorithmType =
+    ExperimentAlgorithm::EnumerationAlgorithm("enum-greedy", EnumerateGreedy::algorithm);
+
+struct EnumerateGreedy<'a> {
+    instance: &'a InstanceType,
+    next_job: usize,
+    loads: Vec<i64>,
+}
+
+impl<'a> EnumerateGreedy<'a> {
+    pub fn new(input: &'a InstanceType) -> Self {
+        Self {
+            instance: input,
+            next_job: 0,
+            loads: vec![0; input.environment.machines as usize],
+        }
+    }
+
+    fn algorithm(input: &InstanceType) -> PreparedEnumerationAlgorithm<'_, SchedulePartial> {
+        Box::new(EnumerateGreedy::new(input))
+    }
+}
+
+impl Iterator for EnumerateGreedy<'_> {
+    type Item = SchedulePartial;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let job = self.instance.jobs.get(self.next_job)?;
+        self.next_job += 1;
+        Some(schedule_greedily(&job.operations, job.id, &mut self.loads))
+    }
+}
+
+/// Total time algorithm for R||C_max with greedy list scheduling
+pub const SOLVE_GREEDY: AlgorithmType =
+    ExperimentAlgorithm::TotalTimeAlgorithm("total-greedy", solve_greedy);
+
+fn solve_greedy(input: &InstanceType) -> Vec<SchedulePartial> {
+    let mut loads = vec![0; input.environment.machines as usize];
+    input
+        .jobs
+        .iter()
+        .map(|j| schedule_greedily(&j.operations, j.id, &mut loads))
+        .collect()
+}
+
+/// Schedule the job with the given processing times on the machine where it completes first
+/// and update that machine's load.
+fn schedule_greedily(processing_times: &[i32], job: u32, loads: &mut [i64]) -> SchedulePartial {
+    let (machine, completion_time) = loads
+        .iter()
+        .zip(processing_times)
+        .map(|(&load, &p)| load + i64::from(p))
+        .enumerate()
+        .min_by_key(|&(machine, completion_time)| (completion_time, machine))
+        .expect("there must be at least one machine");
+
+    let time = loads[machine];
+    loads[machine] = completion_time;
+    SchedulePartial {
+        job,
+        machine: machine as u32 + 1,
+        time,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        algorithms::scheduling::{makespan, validate_schedule},
+        data_structures::scheduling_problems::Job,
+        experiments::assert_enum_matches_total,
+    };
+
+    use super::*;
+
+    // First entry is the job id, then come the processing times on machine 1 and 2.
+    const INSTANCE: [(u32, i32, i32); 4] = [(0, 3, 5), (1, 2, 1), (2, 4, 4), (3, 6, 2)];
+
+    // The greedy schedule for the above instance, computed by hand:
+    // job, machine and start time.
+    const SOLUTION: [(u32, u32, i64); 4] = [(0, 1, 0), (1, 2, 0), (2, 2, 1), (3, 2, 5)];
+
+    fn instance() -> InstanceType {
+        SchedulingInstance {
+            environment: UnrelatedMachines { machines: 2 },
+            jobs: INSTANCE
+                .iter()
+                .map(|j| Job::new(j.0, vec![j.1, j.2], (), ()))
+                .collect(),
+            precedences: (),
+        }
+    }
+
+    fn solution() -> Vec<SchedulePartial> {
+        SOLUTION
+            .iter()
+            .map(|&(job, machine, time)| SchedulePartial { job, machine, time })
+            .collect()
+    }
+
+    #[test]
+    fn test_r_cmax_enumeration() {
+        let instance = instance();
+        let schedule: Vec<_> = EnumerateGreedy::new(&instance).collect();
+        assert_eq!(validate_schedule(&instance, &schedule), Ok(()));
+
+        assert_eq!(schedule, solution());
+        assert_eq!(makespan(&instance, &schedule), 7);
+    }
+
+    #[test]
+    fn test_r_cmax_total_time() {
+        let instance = instance();
+        let schedule = solve_greedy(&instance);
+        assert_eq!(validate_schedule(&instance, &schedule), Ok(()));
+
+        assert_eq!(schedule, solution());
+        assert_eq!(makespan(&instance, &schedule), 7);
+    }
+
+    #[test]
+    fn test_r_cmax_enumeration_matches_total_time() {
+        let instance = instance();
+        assert_enum_matches_total(
+            &instance,
+            &ENUMERATE_GREEDY,
+            &SOLVE_GREEDY,
+            |schedule: &Vec<SchedulePartial>| makespan(&instance, schedule),
+        );
+    }
+
+    #[test]
+    fn test_makespan_empty() {
+        let instance = SchedulingInstance {
+            environment: UnrelatedMachines { machines: 3 },
+            jobs: Vec::new(),
+            precedences: (),
+        };
+        assert_eq!(makespan(&instance, &solve_greedy(&instance)), 0);
+    }
+}
@@ -1,8 +1,64 @@
+pub mod lmax;
 pub mod prec_cmax;
 pub mod rj_cmax;
 
-#[derive(Debug, Clone, Copy, PartialEq)]
+use std::cmp::Ordering;
+
+use super::ScheduleEntry;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct SchedulePartial {
     pub job: u32,
     pub time: i64,
 }
+
+/// Partials are ordered by time, then job.
+impl Ord for SchedulePartial {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (self.time, self.job).cmp(&(other.time, other.job))
+    }
+}
+
+impl PartialOrd for SchedulePartial {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl ScheduleEntry for SchedulePartial {
+    fn job(&self) -> u32 {
+        self.job
+    }
+
+    fn machine(&self) -> u32 {
+        1
+    }
+
+    fn start_time(&self) -> i64 {
+        self.time
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_ordering() {
+        let mut schedule = [
+            SchedulePartial { job: 2, time: 5 },
+            SchedulePartial { job: 7, time: 0 },
+            SchedulePartial { job: 1, time: 5 },
+        ];
+        schedule.sort_unstable();
+
+        assert_eq!(
+            schedule,
+            [
+                SchedulePartial { job: 7, time: 0 },
+                SchedulePartial { job: 1, time: 5 },
+                SchedulePartial { job: 2, time: 5 },
+            ]
+        );
+    }
+}
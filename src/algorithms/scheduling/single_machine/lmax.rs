@@ -0,0 +1,159 @@
+//! Exact algorithms for 1||L_max
+//!
+//! Minimize the maximum lateness by scheduling in order of non-decreasing deadlines
+//! without idle time (earliest due date first, EDD) \[1\].
+//!
+//! \[1\] J. R. Jackson, “Scheduling a production line to minimize maximum tardiness,” Research Report 43, Management Science Research Project, University of California, Los Angeles, 1955.
+
+use crate::{
+    algorithms::sorting::IQS,
+    data_structures::scheduling_problems::{Job, SchedulingInstance, SingleMachine},
+    experiments::{ExperimentAlgorithm, PreparedEnumerationAlgorithm},
+};
+
+use super::SchedulePartial;
+
+type InstanceType = SchedulingInstance<SingleMachine, i32, i32>;
+pub type AlgorithmType = ExperimentAlgorithm<InstanceType, SchedulePartial, Vec<SchedulePartial>>;
+
+/// Returns the maximum lateness `max_j (C_j - d_j)` of the schedule, or 0 for an empty schedule.
+///
+/// Note that this assumes that the index of a job in the jobs vector and the job's id are identical.
+pub fn max_lateness(instance: &InstanceType, schedule: &[SchedulePartial]) -> i64 {
+    schedule
+        .iter()
+        .map(|p| {
+            let job = &instance.jobs[p.job as usize];
+            p.time + i64::from(job.operations[0]) - i64::from(job.deadline)
+        })
+        .max()
+        .unwrap_or(0)
+}
+
+/// Enumeration algorithm for 1||L_max with IQS for incremental sorting
+pub const ENUMERATE_WITH_IQS: AlgorithmType =
+    ExperimentAlgorithm::EnumerationAlgorithm("enum-iqs", EnumerateWithIQS::algorithm);
+
+struct EnumerateWithIQS<'a> {
+    iqs: IQS<&'a Job<i32, i32>>,
+    time: i64,
+}
+
+impl<'a> EnumerateWithIQS<'a> {
+    pub fn new(input: &'a InstanceType) -> Self {
+        let sortable_jobs: Vec<&Job<i32, i32>> = input.jobs.iter().collect();
+        let iqs = IQS::with_comparator(&sortable_jobs, |j1, j2| j1.deadline.cmp(&j2.deadline));
+        Self { iqs, time: 0 }
+    }
+
+    fn algorithm(input: &InstanceType) -> PreparedEnumerationAlgorithm<'_, SchedulePartial> {
+        Box::new(EnumerateWithIQS::new(input))
+    }
+}
+
+impl Iterator for EnumerateWithIQS<'_> {
+    type Item = SchedulePartial;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iqs.next().map(|j| {
+            let start_time = self.time;
+            self.time += i64::from(j.operations[0]);
+            SchedulePartial {
+                job: j.id,
+                time: start_time,
+            }
+        })
+    }
+}
+
+/// Total time algorithm for 1||L_max with rust's sort_unstable_by_key
+pub const SOLVE_WITH_UNSTABLE_SORT: AlgorithmType =
+    ExperimentAlgorithm::TotalTimeAlgorithm("total-unstable-sort", rust_unstable_sort);
+
+fn rust_unstable_sort(input: &InstanceType) -> Vec<SchedulePartial> {
+    let mut sortable_jobs: Vec<&Job<i32, i32>> = input.jobs.iter().collect();
+    sortable_jobs.sort_unstable_by_key(|j| j.deadline);
+
+    let mut schedule = Vec::with_capacity(sortable_jobs.len());
+    let mut time = 0;
+    for j in sortable_jobs {
+        schedule.push(SchedulePartial { job: j.id, time });
+        time += i64::from(j.operations[0]);
+    }
+    schedule
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        algorithms::scheduling::validate_schedule, experiments::assert_enum_matches_total,
+    };
+
+    use super::*;
+
+    // First entry is the job id, then come the processing time and the deadline.
+    const INSTANCE: [(u32, i32, i32); 4] = [(0, 3, 9), (1, 2, 3), (2, 4, 7), (3, 1, 10)];
+
+    // The EDD schedule for the above instance.
+    // First entry is the job id, second the start time.
+    // Jobs 1 and 2 finish one time unit early, jobs 0 and 3 exactly on time.
+    const SOLUTION: [(u32, i64); 4] = [(1, 0), (2, 2), (0, 6), (3, 9)];
+
+    fn instance() -> InstanceType {
+        SchedulingInstance {
+            environment: SingleMachine,
+            jobs: INSTANCE
+                .iter()
+                .map(|j| Job::new(j.0, vec![j.1], j.2, ()))
+                .collect(),
+            precedences: (),
+        }
+    }
+
+    fn solution() -> Vec<SchedulePartial> {
+        SOLUTION
+            .iter()
+            .map(|&(job, time)| SchedulePartial { job, time })
+            .collect()
+    }
+
+    #[test]
+    fn test_lmax_enumeration() {
+        let instance = instance();
+        let schedule: Vec<_> = EnumerateWithIQS::new(&instance).collect();
+        assert_eq!(validate_schedule(&instance, &schedule), Ok(()));
+
+        assert_eq!(schedule, solution());
+        assert_eq!(max_lateness(&instance, &schedule), 0);
+    }
+
+    #[test]
+    fn test_lmax_total_time() {
+        let instance = instance();
+        let schedule = rust_unstable_sort(&instance);
+        assert_eq!(validate_schedule(&instance, &schedule), Ok(()));
+
+        assert_eq!(schedule, solution());
+        assert_eq!(max_lateness(&instance, &schedule), 0);
+    }
+
+    #[test]
+    fn test_lmax_enumeration_matches_total_time() {
+        let instance = instance();
+        assert_enum_matches_total(
+            &instance,
+            &ENUMERATE_WITH_IQS,
+            &SOLVE_WITH_UNSTABLE_SORT,
+            |schedule: &Vec<SchedulePartial>| max_lateness(&instance, schedule),
+        );
+    }
+
+    #[test]
+    fn test_max_lateness() {
+        let instance = instance();
+        // shortest processing time first: job 2 finishes at 10 with deadline 7
+        let spt = [(3, 0), (1, 1), (0, 3), (2, 6)].map(|(job, time)| SchedulePartial { job, time });
+
+        assert_eq!(max_lateness(&instance, &spt), 3);
+    }
+}
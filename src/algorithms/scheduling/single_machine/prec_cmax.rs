@@ -1,10 +1,15 @@
 //! Exact algorithms for 1|prec|C_max
 //!
 //! Optimize makespan by scheduling in any topological order without idle time.
+//!
+//! All algorithms return a [`CouldNotComputeError`] if the precedences contain a cycle.
 
 use crate::{
-    algorithms::graphs::topological_ordering::{
-        dfs_finish_time, idfs_finish_time, IterativeSourceRemoval,
+    algorithms::{
+        graphs::topological_ordering::{
+            dfs_finish_time, idfs_finish_time, IterativeSourceRemoval, Kahn,
+        },
+        CouldNotComputeError,
     },
     data_structures::{
         graphs::{DirectedAdjacencyArraysGraph, Index},
@@ -18,7 +23,11 @@ use super::SchedulePartial;
 type InstanceType =
     SchedulingInstance<SingleMachine, i32, (), (), DirectedAdjacencyArraysGraph<u32>>;
 
-pub type AlgorithmType = ExperimentAlgorithm<InstanceType, SchedulePartial, Vec<SchedulePartial>>;
+pub type AlgorithmType = ExperimentAlgorithm<
+    InstanceType,
+    Result<SchedulePartial, CouldNotComputeError>,
+    Result<Vec<SchedulePartial>, CouldNotComputeError>,
+>;
 
 /// Enumeration algorithm for 1|prec|C_max with iterative topological ordering.
 ///
@@ -45,24 +54,77 @@ impl<'a> EnumerateWithISR<'a> {
         }
     }
 
-    fn algorithm(input: &InstanceType) -> PreparedEnumerationAlgorithm<SchedulePartial> {
+    fn algorithm(
+        input: &InstanceType,
+    ) -> PreparedEnumerationAlgorithm<'_, Result<SchedulePartial, CouldNotComputeError>> {
         Box::new(EnumerateWithISR::new(input))
     }
 }
 
 impl Iterator for EnumerateWithISR<'_> {
-    type Item = SchedulePartial;
+    type Item = Result<SchedulePartial, CouldNotComputeError>;
 
     fn next(&mut self) -> Option<Self::Item> {
         self.isr.next().map(|r| {
-            let job = r.expect("Precedence graph should not include cycles.");
+            let job = r?;
+            let j = &self.instance.jobs[job.index()];
+            let start_time = self.time;
+            self.time += i64::from(j.operations[0]);
+            Ok(SchedulePartial {
+                job,
+                time: start_time,
+            })
+        })
+    }
+}
+
+/// Enumeration algorithm for 1|prec|C_max with Kahn's algorithm for topological ordering.
+///
+/// Ties between available jobs are broken by smallest job id.
+///
+/// Note that the algorithm assumes that the index of a job in the jobs vector,
+/// the job's id and the corresponding vertex id in the precedence graph are all identical.
+/// The precedence graph is also expected to have exactly one vertex per job.
+/// *No checks are made to verify those assumptions!*
+pub const ENUMERATE_WITH_KAHN: AlgorithmType =
+    ExperimentAlgorithm::EnumerationAlgorithm("enum-kahn", EnumerateWithKahn::algorithm);
+
+struct EnumerateWithKahn<'a> {
+    kahn: Kahn<'a, u32, DirectedAdjacencyArraysGraph<u32>>,
+    instance: &'a InstanceType,
+    time: i64,
+}
+
+impl<'a> EnumerateWithKahn<'a> {
+    pub fn new(input: &'a InstanceType) -> Self {
+        let kahn = Kahn::new(&input.precedences);
+        Self {
+            kahn,
+            instance: input,
+            time: 0,
+        }
+    }
+
+    fn algorithm(
+        input: &InstanceType,
+    ) -> PreparedEnumerationAlgorithm<'_, Result<SchedulePartial, CouldNotComputeError>> {
+        Box::new(EnumerateWithKahn::new(input))
+    }
+}
+
+impl Iterator for EnumerateWithKahn<'_> {
+    type Item = Result<SchedulePartial, CouldNotComputeError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.kahn.next().map(|r| {
+            let job = r?;
             let j = &self.instance.jobs[job.index()];
             let start_time = self.time;
             self.time += i64::from(j.operations[0]);
-            SchedulePartial {
+            Ok(SchedulePartial {
                 job,
                 time: start_time,
-            }
+            })
         })
     }
 }
@@ -76,9 +138,10 @@ impl Iterator for EnumerateWithISR<'_> {
 pub const SOLVE_WITH_IDFS_FINISH_TIME: AlgorithmType =
     ExperimentAlgorithm::TotalTimeAlgorithm("total-idfs-finish-time", order_by_idfs_finish_time);
 
-fn order_by_idfs_finish_time(input: &InstanceType) -> Vec<SchedulePartial> {
-    let order =
-        idfs_finish_time(&input.precedences).expect("Precedence graph should not include cycles.");
+fn order_by_idfs_finish_time(
+    input: &InstanceType,
+) -> Result<Vec<SchedulePartial>, CouldNotComputeError> {
+    let order = idfs_finish_time(&input.precedences)?;
 
     let mut schedule = Vec::with_capacity(input.jobs.len());
     let mut time = 0;
@@ -86,7 +149,7 @@ fn order_by_idfs_finish_time(input: &InstanceType) -> Vec<SchedulePartial> {
         schedule.push(SchedulePartial { job, time });
         time += i64::from(input.jobs[job.index()].operations[0]);
     }
-    schedule
+    Ok(schedule)
 }
 
 /// Total time algorithm for 1|prec|C_max with topological ordering via dfs finish time.
@@ -98,9 +161,10 @@ fn order_by_idfs_finish_time(input: &InstanceType) -> Vec<SchedulePartial> {
 pub const SOLVE_WITH_DFS_FINISH_TIME: AlgorithmType =
     ExperimentAlgorithm::TotalTimeAlgorithm("total-dfs-finish-time", order_by_dfs_finish_time);
 
-fn order_by_dfs_finish_time(input: &InstanceType) -> Vec<SchedulePartial> {
-    let order =
-        dfs_finish_time(&input.precedences).expect("Precedence graph should not include cycles.");
+fn order_by_dfs_finish_time(
+    input: &InstanceType,
+) -> Result<Vec<SchedulePartial>, CouldNotComputeError> {
+    let order = dfs_finish_time(&input.precedences)?;
 
     let mut schedule = Vec::with_capacity(input.jobs.len());
     let mut time = 0;
@@ -108,7 +172,7 @@ fn order_by_dfs_finish_time(input: &InstanceType) -> Vec<SchedulePartial> {
         schedule.push(SchedulePartial { job, time });
         time += i64::from(input.jobs[job.index()].operations[0]);
     }
-    schedule
+    Ok(schedule)
 }
 
 #[cfg(test)]
@@ -132,16 +196,38 @@ mod test {
             environment: SingleMachine,
             jobs: JOBS
                 .iter()
-                .map(|j| Job {
-                    id: j.0,
-                    operations: vec![j.1],
-                    deadline: (),
-                    release_time: (),
-                })
+                .map(|j| Job::new(j.0, vec![j.1], (), ()))
                 .collect(),
             precedences: graph,
         };
-        let schedule: Vec<_> = EnumerateWithISR::new(&instance).collect();
+        let schedule: Vec<_> = EnumerateWithISR::new(&instance)
+            .collect::<Result<_, _>>()
+            .unwrap();
+
+        assert_eq!(
+            schedule,
+            SOLUTION.map(|s| SchedulePartial {
+                job: s.0,
+                time: s.1,
+            }),
+        )
+    }
+
+    #[test]
+    fn test_prec_cmax_enumeration_kahn() {
+        let graph = DirectedEdgeListGraph::new(5, EDGES.into());
+        let graph = DirectedAdjacencyArraysGraph::from(&graph);
+        let instance = SchedulingInstance {
+            environment: SingleMachine,
+            jobs: JOBS
+                .iter()
+                .map(|j| Job::new(j.0, vec![j.1], (), ()))
+                .collect(),
+            precedences: graph,
+        };
+        let schedule: Vec<_> = EnumerateWithKahn::new(&instance)
+            .collect::<Result<_, _>>()
+            .unwrap();
 
         assert_eq!(
             schedule,
@@ -160,16 +246,11 @@ mod test {
             environment: SingleMachine,
             jobs: JOBS
                 .iter()
-                .map(|j| Job {
-                    id: j.0,
-                    operations: vec![j.1],
-                    deadline: (),
-                    release_time: (),
-                })
+                .map(|j| Job::new(j.0, vec![j.1], (), ()))
                 .collect(),
             precedences: graph,
         };
-        let schedule: Vec<_> = order_by_idfs_finish_time(&instance);
+        let schedule: Vec<_> = order_by_idfs_finish_time(&instance).unwrap();
 
         assert_eq!(
             schedule,
@@ -188,16 +269,11 @@ mod test {
             environment: SingleMachine,
             jobs: JOBS
                 .iter()
-                .map(|j| Job {
-                    id: j.0,
-                    operations: vec![j.1],
-                    deadline: (),
-                    release_time: (),
-                })
+                .map(|j| Job::new(j.0, vec![j.1], (), ()))
                 .collect(),
             precedences: graph,
         };
-        let schedule: Vec<_> = order_by_dfs_finish_time(&instance);
+        let schedule: Vec<_> = order_by_dfs_finish_time(&instance).unwrap();
 
         assert_eq!(
             schedule,
@@ -207,4 +283,32 @@ mod test {
             }),
         )
     }
+
+    #[test]
+    fn test_prec_cmax_cyclic_precedences() {
+        // 3 -> 4 -> 2 -> 3 is a cycle
+        let graph = DirectedEdgeListGraph::new(5, [EDGES.as_slice(), &[(2, 3)]].concat().into());
+        let graph = DirectedAdjacencyArraysGraph::from(&graph);
+        let instance = SchedulingInstance {
+            environment: SingleMachine,
+            jobs: JOBS
+                .iter()
+                .map(|j| Job::new(j.0, vec![j.1], (), ()))
+                .collect(),
+            precedences: graph,
+        };
+
+        for algorithm in [ENUMERATE_WITH_TOPO_SORT, ENUMERATE_WITH_KAHN] {
+            let ExperimentAlgorithm::EnumerationAlgorithm(_, enumerate) = algorithm else {
+                unreachable!();
+            };
+            let partials: Vec<_> = enumerate(&instance).collect();
+            // job 0 is scheduled before the cycle is found, which ends the enumeration
+            assert_eq!(partials.len(), 2);
+            assert!(partials[0].is_ok());
+            assert!(partials[1].is_err());
+        }
+        assert!(order_by_idfs_finish_time(&instance).is_err());
+        assert!(order_by_dfs_finish_time(&instance).is_err());
+    }
 }
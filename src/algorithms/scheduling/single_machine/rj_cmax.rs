@@ -31,7 +31,7 @@ impl<'a> EnumerateWithIQS<'a> {
         Self { iqs, time: 0 }
     }
 
-    fn algorithm(input: &InstanceType) -> PreparedEnumerationAlgorithm<SchedulePartial> {
+    fn algorithm(input: &InstanceType) -> PreparedEnumerationAlgorithm<'_, SchedulePartial> {
         Box::new(EnumerateWithIQS::new(input))
     }
 }
@@ -74,6 +74,8 @@ fn rust_unstable_sort(input: &InstanceType) -> Vec<SchedulePartial> {
 
 #[cfg(test)]
 mod test {
+    use crate::algorithms::scheduling::validate_schedule;
+
     use super::*;
 
     // At the time of writing the tests, this is the instance generated with
@@ -98,17 +100,13 @@ mod test {
             environment: SingleMachine,
             jobs: INSTANCE
                 .iter()
-                .map(|j| Job {
-                    id: j.0,
-                    operations: vec![j.1],
-                    deadline: (),
-                    release_time: j.2,
-                })
+                .map(|j| Job::new(j.0, vec![j.1], (), j.2))
                 .collect(),
             precedences: (),
         };
 
         let schedule: Vec<_> = EnumerateWithIQS::new(&instance).collect();
+        assert_eq!(validate_schedule(&instance, &schedule), Ok(()));
 
         assert_eq!(
             schedule,
@@ -124,17 +122,43 @@ mod test {
             environment: SingleMachine,
             jobs: INSTANCE
                 .iter()
-                .map(|j| Job {
-                    id: j.0,
-                    operations: vec![j.1],
-                    deadline: (),
-                    release_time: j.2,
-                })
+                .map(|j| Job::new(j.0, vec![j.1], (), j.2))
                 .collect(),
             precedences: (),
         };
 
         let schedule = rust_unstable_sort(&instance);
+        assert_eq!(validate_schedule(&instance, &schedule), Ok(()));
+
+        assert_eq!(
+            schedule,
+            SOLUTION.map(|s| SchedulePartial {
+                job: s.0,
+                time: s.1,
+            })
+        );
+    }
+
+    #[test]
+    fn test_rj_cmax_enumeration_on_other_thread() {
+        let instance = SchedulingInstance {
+            environment: SingleMachine,
+            jobs: INSTANCE
+                .iter()
+                .map(|j| Job::new(j.0, vec![j.1], (), j.2))
+                .collect(),
+            precedences: (),
+        };
+
+        let ExperimentAlgorithm::EnumerationAlgorithm(_, algorithm) = ENUMERATE_WITH_IQS else {
+            panic!("ENUMERATE_WITH_IQS should be an enumeration algorithm.");
+        };
+        let enumeration = algorithm(&instance);
+        let schedule = std::thread::scope(|s| {
+            s.spawn(move || enumeration.collect::<Vec<_>>())
+                .join()
+                .unwrap()
+        });
 
         assert_eq!(
             schedule,
@@ -0,0 +1,181 @@
+/// Sort the given values with counting sort in time O(n + max_value).
+///
+/// # Panics
+///
+/// Panics if any value is larger than `max_value`.
+pub fn counting_sort(elements: &[u32], max_value: u32) -> Vec<u32> {
+    let counts = count(elements, max_value);
+
+    let mut sorted = Vec::with_capacity(elements.len());
+    for (value, &count) in counts.iter().enumerate() {
+        sorted.extend(std::iter::repeat_n(value as u32, count));
+    }
+    sorted
+}
+
+/// Stably sort the given items by the key extracted with `key` in time O(n + max_key).
+///
+/// Items with equal keys keep their relative order from the input.
+///
+/// ```
+/// use exp_lib::algorithms::sorting::counting_sort_by_key;
+///
+/// let sorted = counting_sort_by_key(&[(2, 'a'), (0, 'b'), (2, 'c'), (1, 'd')], 2, |x| x.0);
+/// assert_eq!(sorted, [(0, 'b'), (1, 'd'), (2, 'a'), (2, 'c')]);
+/// ```
+///
+/// # Panics
+///
+/// Panics if any key is larger than `max_key`.
+pub fn counting_sort_by_key<T, F>(items: &[T], max_key: usize, key: F) -> Vec<T>
+where
+    T: Clone,
+    F: Fn(&T) -> usize,
+{
+    let keys: Vec<usize> = items.iter().map(key).collect();
+
+    // offsets[k] is the position of the next item with key k in the sorted output
+    let mut offsets = vec![0; max_key + 1];
+    for &k in &keys {
+        assert!(k <= max_key, "Key {} exceeds maximum {}.", k, max_key);
+        offsets[k] += 1;
+    }
+    let mut current_offset = 0;
+    for entry in offsets.iter_mut() {
+        let count = *entry;
+        *entry = current_offset;
+        current_offset += count;
+    }
+
+    let mut order = vec![0; items.len()];
+    for (i, &k) in keys.iter().enumerate() {
+        order[offsets[k]] = i;
+        offsets[k] += 1;
+    }
+
+    order.into_iter().map(|i| items[i].clone()).collect()
+}
+
+/// Incremental Counting Sort
+///
+/// Counts the occurrences of each value in time O(n + max_value) and then yields the values in sorted order
+/// by walking through the buckets. The delay between two outputs is in O(max_value) in the worst case,
+/// but the total time after preprocessing is O(n + max_value).
+///
+/// ```
+/// use exp_lib::algorithms::sorting::IncrementalCountingSort;
+///
+/// let sorted: Vec<u32> = IncrementalCountingSort::new(&[3, 1, 4, 1, 5, 9, 2, 6], 9).collect();
+/// assert_eq!(sorted, [1, 1, 2, 3, 4, 5, 6, 9]);
+/// ```
+pub struct IncrementalCountingSort {
+    counts: Vec<usize>,
+    value: usize,
+}
+
+impl IncrementalCountingSort {
+    /// # Panics
+    ///
+    /// Panics if any value is larger than `max_value`.
+    pub fn new(elements: &[u32], max_value: u32) -> Self {
+        Self {
+            counts: count(elements, max_value),
+            value: 0,
+        }
+    }
+}
+
+impl Iterator for IncrementalCountingSort {
+    type Item = u32;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        // find the next non-empty bucket
+        while self.counts.get(self.value).is_some_and(|&c| c == 0) {
+            self.value += 1;
+        }
+
+        let count = self.counts.get_mut(self.value)?;
+        *count -= 1;
+        Some(self.value as u32)
+    }
+}
+
+fn count(elements: &[u32], max_value: u32) -> Vec<usize> {
+    let mut counts = vec![0; max_value as usize + 1];
+    for &x in elements {
+        assert!(x <= max_value, "Value {} exceeds maximum {}.", x, max_value);
+        counts[x as usize] += 1;
+    }
+    counts
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const PAREDES_NAVARRO: [u32; 16] = [
+        49, 81, 74, 12, 58, 92, 86, 33, 67, 18, 25, 37, 51, 63, 29, 41,
+    ];
+    const DUPLICATES: [u32; 10] = [3, 0, 3, 7, 1, 0, 7, 7, 2, 3];
+
+    #[test]
+    fn test_counting_sort() {
+        assert_eq!(
+            counting_sort(&PAREDES_NAVARRO, 99),
+            [12, 18, 25, 29, 33, 37, 41, 49, 51, 58, 63, 67, 74, 81, 86, 92]
+        );
+        assert_eq!(
+            counting_sort(&DUPLICATES, 7),
+            [0, 0, 1, 2, 3, 3, 3, 7, 7, 7]
+        );
+        assert!(counting_sort(&[], 7).is_empty());
+    }
+
+    #[test]
+    fn test_incremental_counting_sort() {
+        for (elements, max_value) in [(&PAREDES_NAVARRO[..], 99), (&DUPLICATES[..], 7), (&[], 0)] {
+            let sorted: Vec<u32> = IncrementalCountingSort::new(elements, max_value).collect();
+
+            assert!(
+                sorted.windows(2).all(|p| p[0] <= p[1]),
+                "Output not sorted."
+            );
+            assert_eq!(sorted, counting_sort(elements, max_value));
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_counting_sort_value_too_large() {
+        counting_sort(&DUPLICATES, 6);
+    }
+
+    #[test]
+    fn test_counting_sort_by_key_stable() {
+        let items: Vec<(u32, usize)> = DUPLICATES.iter().copied().zip(0..).collect();
+        let sorted = counting_sort_by_key(&items, 7, |&(value, _)| value as usize);
+
+        assert_eq!(
+            sorted.iter().map(|&(value, _)| value).collect::<Vec<_>>(),
+            counting_sort(&DUPLICATES, 7)
+        );
+        assert!(
+            sorted
+                .windows(2)
+                .all(|p| p[0].0 < p[1].0 || (p[0].0 == p[1].0 && p[0].1 < p[1].1)),
+            "Equal keys not in input order."
+        );
+    }
+
+    #[test]
+    fn test_counting_sort_by_key_empty() {
+        let items: [u32; 0] = [];
+        assert!(counting_sort_by_key(&items, 0, |&x| x as usize).is_empty());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_counting_sort_by_key_too_large() {
+        counting_sort_by_key(&DUPLICATES, 6, |&x| x as usize);
+    }
+}
@@ -0,0 +1,329 @@
+use std::cmp::Ordering;
+
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+/// How [`IQS`] chooses the pivot element of a partition.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PivotStrategy {
+    /// The last element
+    #[default]
+    Last,
+    /// The median of the first, middle and last element
+    MedianOfThree,
+    /// A uniformly random element; the random choices are determined by the given seed
+    Random(u64),
+}
+
+/// Pivot strategy including the state it needs
+enum Pivot {
+    Last,
+    MedianOfThree,
+    Random(Box<StdRng>),
+}
+
+/// Incremental Quick Sort
+///
+/// This is an implementation of the incremental sorting algorithm by Paredes and Navarro \[1\].
+///
+/// By default we use a fixed pivot element (last element in the array) without protection against bad pivot choice.
+/// Use [`IQS::with_pivot_strategy`] for a more robust [`PivotStrategy`], e.g. on (almost) sorted inputs.
+///
+/// `IQS` is used as iterator:
+///
+/// ```
+/// use exp_lib::algorithms::sorting::IQS;
+///
+/// let iqs = IQS::new(&[49, 81, 74, 12, 58, 92, 86, 33, 67, 18, 25, 37, 51, 63, 29, 41]);
+/// let mut sorted = Vec::new();
+/// for x in iqs {
+///     println!("{}", x);
+///     sorted.push(x);
+/// }
+/// assert_eq!(sorted, [12, 18, 25, 29, 33, 37, 41, 49, 51, 58, 63, 67, 74, 81, 86, 92]);
+/// ```
+///
+/// \[1\] R. Paredes and G. Navarro, “Optimal Incremental Sorting,” in 2006 Proceedings of the Workshop on Algorithm Engineering and Experiments (ALENEX), in Proceedings. , Society for Industrial and Applied Mathematics, 2006, pp. 171–182. doi: [10.1137/1.9781611972863.16](https://doi.org/10.1137/1.9781611972863.16).
+pub struct IQS<T> {
+    pub a: Vec<T>,
+    compare: fn(&T, &T) -> Ordering,
+    idx: usize,
+    s: Vec<usize>,
+    pivot: Pivot,
+}
+
+impl<T> IQS<T>
+where
+    T: Copy,
+{
+    pub fn new(elements: &[T]) -> Self
+    where
+        T: Ord + 'static,
+    {
+        Self::with_comparator(elements, T::cmp)
+    }
+
+    pub fn with_comparator(elements: &[T], compare: fn(&T, &T) -> Ordering) -> Self {
+        Self::with_pivot_strategy(elements, compare, PivotStrategy::Last)
+    }
+
+    pub fn with_pivot_strategy(
+        elements: &[T],
+        compare: fn(&T, &T) -> Ordering,
+        strategy: PivotStrategy,
+    ) -> Self {
+        Self {
+            s: vec![elements.len()],
+            a: elements.to_vec(),
+            compare,
+            idx: 0,
+            pivot: match strategy {
+                PivotStrategy::Last => Pivot::Last,
+                PivotStrategy::MedianOfThree => Pivot::MedianOfThree,
+                PivotStrategy::Random(seed) => Pivot::Random(Box::new(StdRng::seed_from_u64(seed))),
+            },
+        }
+    }
+}
+
+/// Return the `k` smallest elements in sorted order, or all elements if there are fewer than `k`.
+///
+/// Uses [`IQS`], so only the part of the input needed for the first `k` elements gets sorted.
+///
+/// ```
+/// use exp_lib::algorithms::sorting::top_k;
+///
+/// assert_eq!(top_k(&[49, 81, 74, 12, 58, 92, 86, 33], 3, u32::cmp), [12, 33, 49]);
+/// ```
+pub fn top_k<T>(elements: &[T], k: usize, compare: fn(&T, &T) -> Ordering) -> Vec<T>
+where
+    T: Copy,
+{
+    IQS::with_comparator(elements, compare).take(k).collect()
+}
+
+impl Pivot {
+    /// Move the chosen pivot element of the non-empty slice `a` to the last position.
+    fn move_to_end<T>(&mut self, a: &mut [T], compare: fn(&T, &T) -> Ordering) {
+        let last = a.len() - 1;
+        let pivot = match self {
+            Pivot::Last => last,
+            Pivot::MedianOfThree => {
+                let (first, mid) = (0, last / 2);
+                let (lo, hi) = if compare(&a[first], &a[mid]).is_le() {
+                    (first, mid)
+                } else {
+                    (mid, first)
+                };
+                if compare(&a[last], &a[lo]).is_lt() {
+                    lo
+                } else if compare(&a[hi], &a[last]).is_lt() {
+                    hi
+                } else {
+                    last
+                }
+            }
+            Pivot::Random(rng) => rng.gen_range(0..=last),
+        };
+        a.swap(pivot, last);
+    }
+}
+
+impl<T> Iterator for IQS<T>
+where
+    T: Copy,
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.idx >= self.a.len() {
+            // we're done; everything's sorted
+            None
+        } else {
+            // keep sorting
+            let mut top = *self.s.last().expect("stack can't run empty");
+
+            // run until a[0..=idx] is sorted
+            while self.idx != top {
+                let slice = &mut self.a[self.idx..top];
+                self.pivot.move_to_end(slice, self.compare);
+                let pidx = partition(slice, self.compare) + self.idx;
+                self.s.push(pidx);
+                top = pidx;
+            }
+
+            self.s.pop(); // pivot element has served its purpose
+            self.idx += 1; // for next invocation of this function
+            Some(self.a[top]) // return next sorted element
+        }
+    }
+}
+
+/// Partition slice `a` by a pivot and return the index of the pivot
+///
+/// Implementation is an adaptation of CLRS 4th edition / Chapter 7:
+///
+/// ```pseudo
+/// PARTITION(A, p, r)
+///   x = A[r]
+///   i = p - 1
+///   for j = p to r - 1
+///     if A[j] <= x
+///       i = i + 1
+///       exchange A[i] with A[j]
+///   exchange A[i+1] with A[r]
+///   return i + 1
+/// ```
+/// Our changes:
+/// - we use a slice instead of explicit bounds
+/// - we partition by < instead of <=, so after the first partition phase elements left of pivot are strictly smaller
+/// - i is one smaller than in CLRS version (left bound is 0 and usize can't hold -1)
+/// - we add a second phase: after the initial partition we find and return the "most central" element that is "equal"
+///   to pivot element to avoid worst-case recursion in case there are lots of equal elements.
+///
+/// # Panics
+///
+/// Panics if slice is empty.
+fn partition<T>(a: &mut [T], compare: fn(&T, &T) -> Ordering) -> usize
+where
+    T: Copy,
+{
+    // `pivot_value` is x in the CLRS partition pseudocode
+    let pivot_value = *a.last().expect("slice must not be empty");
+
+    // i tracks a possible pivot position
+    let mut i = 0;
+    // j looks for elements smaller than pivot that need to be left of the pivot
+    for j in 0..a.len() - 1 {
+        if compare(&a[j], &pivot_value).is_lt() {
+            // element in position j is smaller than pivot
+            // move it to the current possible pivot position
+            a.swap(i, j);
+            // next possible pivot position is now to the right of the swapped element
+            i += 1;
+        }
+    }
+
+    // swap pivot element to the confirmed pivot position i
+    a.swap(i, a.len() - 1);
+    // all elements left of i are smaller than pivot according to the comparator
+    let leftmost_pivot_position = i;
+
+    // There might be (a lot) of elements right of this pivot position that are
+    // equal to the pivot in terms of the comparator.
+    // this might result in a bad partition with only a constant number of elements
+    // left of the pivot and a linear number of elements right of it.
+
+    // we would like the pivot to be in the middle.
+    let mid_position = a.len() / 2;
+
+    // if the pivot is already right of or at the middle position, we don't want to go further to the right.
+    if mid_position <= leftmost_pivot_position {
+        return leftmost_pivot_position;
+    }
+
+    // pivot is in the left half, so let's try to find a better pivot position:
+    // - let l be the leftmost_pivot_position as computed above
+    // - assume the rightmost element equal to pivot is in position r
+    // - we have to choose a pivot in the slice [l..=r]
+    // - we do not want to go farther to the right than the `mid_position` m
+    a[leftmost_pivot_position + 1..=mid_position]
+        .iter()
+        // if r < m, the following position search will find the larger element in position (r + 1);
+        // but as our search slice starts at l+1, this actually returns Some(r + 1 - (l + 1)) as index
+        // todo special pos function
+        .position(|y| compare(&pivot_value, y).is_lt())
+        // we shift this index back to the actual index of r in a
+        .map(|p| p + leftmost_pivot_position)
+        // if r >= m, the search will yield None and we want to take the mid_position
+        .unwrap_or(mid_position)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const CRLS_7_1: [u32; 8] = [2, 8, 7, 1, 3, 5, 6, 4];
+    const PAREDES_NAVARRO: [u32; 16] = [
+        49, 81, 74, 12, 58, 92, 86, 33, 67, 18, 25, 37, 51, 63, 29, 41,
+    ];
+
+    #[test]
+    fn test_partition_crls_7_1() {
+        let mut a = CRLS_7_1;
+
+        let pidx = partition(&mut a, u32::cmp);
+
+        assert_eq!(pidx, 3);
+        assert_eq!(a, [2, 1, 3, 4, 7, 5, 6, 8]);
+    }
+
+    #[test]
+    fn test_partition_equal_elements() {
+        let mut a = vec![0, 0, 0, 0, 0];
+        let pidx = partition(&mut a, i32::cmp);
+
+        assert_eq!(pidx, 2);
+        assert_eq!(a, [0, 0, 0, 0, 0]);
+    }
+
+    #[test]
+    fn test_sort_crls_7_1() {
+        let iqs = IQS::new(&CRLS_7_1);
+        let sorted: Vec<u32> = iqs.collect();
+        assert_eq!(sorted, [1, 2, 3, 4, 5, 6, 7, 8]);
+    }
+
+    #[test]
+    fn test_sort_paredes_navarro() {
+        let iqs = IQS::new(&PAREDES_NAVARRO);
+        let sorted: Vec<u32> = iqs.collect();
+        assert_eq!(
+            sorted,
+            [12, 18, 25, 29, 33, 37, 41, 49, 51, 58, 63, 67, 74, 81, 86, 92]
+        );
+    }
+
+    #[test]
+    fn test_median_of_three_reverse_sorted() {
+        let elements: Vec<u32> = (0..10_000).rev().collect();
+        let iqs = IQS::with_pivot_strategy(&elements, u32::cmp, PivotStrategy::MedianOfThree);
+        assert!(iqs.eq(0..10_000));
+    }
+
+    #[test]
+    fn test_pivot_strategies() {
+        for strategy in [
+            PivotStrategy::Last,
+            PivotStrategy::MedianOfThree,
+            PivotStrategy::Random(42),
+        ] {
+            let iqs = IQS::with_pivot_strategy(&PAREDES_NAVARRO, u32::cmp, strategy);
+            let sorted: Vec<u32> = iqs.collect();
+            assert_eq!(
+                sorted,
+                [12, 18, 25, 29, 33, 37, 41, 49, 51, 58, 63, 67, 74, 81, 86, 92]
+            );
+
+            let sorted_input: Vec<u32> = (0..1000).collect();
+            let iqs = IQS::with_pivot_strategy(&sorted_input, u32::cmp, strategy);
+            assert!(iqs.eq(0..1000));
+
+            let equal = [5u32; 100];
+            let iqs = IQS::with_pivot_strategy(&equal, u32::cmp, strategy);
+            assert!(iqs.eq(equal));
+        }
+    }
+
+    #[test]
+    fn test_top_k() {
+        for elements in [&CRLS_7_1[..], &PAREDES_NAVARRO[..], &[]] {
+            let mut sorted = elements.to_vec();
+            sorted.sort();
+
+            for k in 0..=elements.len() + 1 {
+                let prefix = &sorted[..k.min(sorted.len())];
+                assert_eq!(top_k(elements, k, u32::cmp), prefix);
+            }
+        }
+    }
+}
@@ -0,0 +1,134 @@
+use std::cmp::Ordering;
+
+/// Incremental Merge Sort
+///
+/// Merges all elements bottom-up in a tournament tree: every inner node holds the smaller of the two elements its
+/// subtrees hold. Building the tree takes O(n), afterwards the root is the smallest element.
+/// Each output removes the winning leaf and replays the matches on its path to the root in O(log n).
+/// Equal elements are output in their input order.
+///
+/// `IncrementalMergeSort` is used as iterator:
+///
+/// ```
+/// use exp_lib::algorithms::sorting::IncrementalMergeSort;
+///
+/// let sorted: Vec<u32> = IncrementalMergeSort::new(&[49, 81, 74, 12, 58, 92, 86, 33]).collect();
+/// assert_eq!(sorted, [12, 33, 49, 58, 74, 81, 86, 92]);
+/// ```
+pub struct IncrementalMergeSort<T> {
+    a: Vec<T>,
+    compare: fn(&T, &T) -> Ordering,
+    /// number of leaves, i.e. the number of elements rounded up to a power of two
+    leaves: usize,
+    /// the tournament tree in heap order (root at 1, children of i at 2i and 2i + 1);
+    /// each node holds the index of the winning element or `None` if its subtree is exhausted
+    tree: Vec<Option<usize>>,
+}
+
+impl<T> IncrementalMergeSort<T>
+where
+    T: Copy,
+{
+    pub fn new(elements: &[T]) -> Self
+    where
+        T: Ord + 'static,
+    {
+        Self::with_comparator(elements, T::cmp)
+    }
+
+    pub fn with_comparator(elements: &[T], compare: fn(&T, &T) -> Ordering) -> Self {
+        let leaves = elements.len().next_power_of_two();
+        let mut tree = vec![None; 2 * leaves];
+        for (i, leaf) in tree[leaves..leaves + elements.len()].iter_mut().enumerate() {
+            *leaf = Some(i);
+        }
+
+        let mut sort = Self {
+            a: elements.to_vec(),
+            compare,
+            leaves,
+            tree,
+        };
+        for node in (1..leaves).rev() {
+            sort.play(node);
+        }
+        sort
+    }
+
+    /// Determine the winner at `node` from the winners of its children.
+    fn play(&mut self, node: usize) {
+        self.tree[node] = match (self.tree[2 * node], self.tree[2 * node + 1]) {
+            (Some(l), Some(r)) => {
+                // ties go to the left to keep equal elements in input order
+                if (self.compare)(&self.a[r], &self.a[l]).is_lt() {
+                    Some(r)
+                } else {
+                    Some(l)
+                }
+            }
+            (l, r) => l.or(r),
+        };
+    }
+}
+
+impl<T> Iterator for IncrementalMergeSort<T>
+where
+    T: Copy,
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let winner = self.tree[1]?;
+
+        // remove the winner and replay its path to the root
+        let mut node = self.leaves + winner;
+        self.tree[node] = None;
+        while node > 1 {
+            node /= 2;
+            self.play(node);
+        }
+
+        Some(self.a[winner])
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const CRLS_7_1: [u32; 8] = [2, 8, 7, 1, 3, 5, 6, 4];
+    const PAREDES_NAVARRO: [u32; 16] = [
+        49, 81, 74, 12, 58, 92, 86, 33, 67, 18, 25, 37, 51, 63, 29, 41,
+    ];
+
+    #[test]
+    fn test_sort_crls_7_1() {
+        let sorted: Vec<u32> = IncrementalMergeSort::new(&CRLS_7_1).collect();
+        assert_eq!(sorted, [1, 2, 3, 4, 5, 6, 7, 8]);
+    }
+
+    #[test]
+    fn test_sort_paredes_navarro() {
+        let sorted: Vec<u32> = IncrementalMergeSort::new(&PAREDES_NAVARRO).collect();
+        assert_eq!(
+            sorted,
+            [12, 18, 25, 29, 33, 37, 41, 49, 51, 58, 63, 67, 74, 81, 86, 92]
+        );
+    }
+
+    #[test]
+    fn test_sort_small() {
+        assert_eq!(IncrementalMergeSort::<u32>::new(&[]).next(), None);
+        assert!(IncrementalMergeSort::new(&[7]).eq([7]));
+        assert!(IncrementalMergeSort::new(&[3, 1, 2]).eq([1, 2, 3]));
+    }
+
+    #[test]
+    fn test_sort_stable() {
+        // pairs of key and input position
+        let elements = [(2, 0), (1, 1), (2, 2), (1, 3), (0, 4), (2, 5)];
+        let sorted: Vec<_> =
+            IncrementalMergeSort::with_comparator(&elements, |a, b| a.0.cmp(&b.0)).collect();
+        assert_eq!(sorted, [(0, 4), (1, 1), (1, 3), (2, 0), (2, 2), (2, 5)]);
+    }
+}
@@ -1,5 +1,10 @@
+use std::time::Duration;
+
 use clap::{Parser, ValueEnum};
-use exp_lib::experiments::sets::{f2_cmax, prec_cmax, rj_cmax, ExperimentOptions, ExperimentSet};
+use exp_lib::{
+    experiments::sets::{f2_cmax, prec_cmax, rj_cmax, ExperimentOptions, ExperimentSet},
+    io::MeasurementFormat,
+};
 
 #[derive(Parser, Debug)]
 #[command(about = "Run experiments or aggregate results for enumeration algorithms.")]
@@ -23,6 +28,30 @@ struct Args {
     /// Write and read generated input instances to/from cache files.
     #[arg(short, long, default_value_t = false)]
     cache_instances: bool,
+
+    /// Abandon runs of an algorithm that take longer than this many seconds.
+    /// The algorithm is then skipped for the remaining runs on that instance.
+    #[arg(short, long, value_parser = parse_timeout)]
+    timeout: Option<Duration>,
+
+    /// Number of unmeasured runs of each algorithm on every instance before the measured runs.
+    #[arg(short, long, default_value_t = 0)]
+    warmup_runs: u32,
+
+    /// File format of the measurement files.
+    #[arg(long, value_enum, default_value_t = Format::Csv)]
+    measurement_format: Format,
+
+    /// Round decimal numbers in aggregated files to this many significant digits.
+    #[arg(long)]
+    float_precision: Option<usize>,
+}
+
+#[derive(Clone, Copy, ValueEnum, Debug)]
+enum Format {
+    Csv,
+    #[clap(name = "jsonl")]
+    JsonLines,
 }
 
 #[derive(Clone, ValueEnum, Debug)]
@@ -36,6 +65,16 @@ enum Set {
     RjCmax,
 }
 
+fn parse_timeout(seconds: &str) -> Result<Duration, String> {
+    let seconds: f64 = seconds.parse().map_err(|why| format!("{}", why))?;
+    Duration::try_from_secs_f64(seconds).map_err(|_| {
+        format!(
+            "{} is not a valid timeout, expected a non-negative number of seconds",
+            seconds
+        )
+    })
+}
+
 fn main() {
     simple_logger::init_with_level(log::Level::Info).unwrap();
 
@@ -47,12 +86,20 @@ fn main() {
         Set::RjCmax => Box::new(rj_cmax::experiment_set()),
     };
 
+    let options = ExperimentOptions {
+        max_size: cli.max_size,
+        cache_instances: cli.cache_instances,
+        per_algorithm_timeout: cli.timeout,
+        warmup_runs: cli.warmup_runs,
+        measurement_format: match cli.measurement_format {
+            Format::Csv => MeasurementFormat::Csv,
+            Format::JsonLines => MeasurementFormat::JsonLines,
+        },
+        float_precision: cli.float_precision,
+    };
     if cli.aggregate {
-        (set.aggregate)();
+        (set.aggregate)(options);
     } else {
-        (set.run)(ExperimentOptions {
-            max_size: cli.max_size,
-            cache_instances: cli.cache_instances,
-        })
+        (set.run)(options);
     }
 }
@@ -1,3 +1,5 @@
+use std::fmt;
+
 mod directed_adjacency_arrays_graph;
 mod directed_edge_list_graph;
 mod direction;
@@ -25,4 +27,55 @@ pub trait DirectedGraph<I: Index> {
     /// Depending on `dir` this will return the successors (out-neighbors)
     /// or the predecessors (in-neighbors) of `v`.
     fn neighbors(&self, v: I, dir: Direction) -> Box<dyn Iterator<Item = I> + '_>;
+
+    /// Returns whether the graph contains the edge `(u, v)`.
+    ///
+    /// The default implementation scans the out-neighbors of `u`.
+    fn has_edge(&self, u: I, v: I) -> bool {
+        self.neighbors(u, Direction::OUT).any(|w| w == v)
+    }
+
+    /// Returns the number of loops, i.e. edges `(v, v)`, of the graph.
+    ///
+    /// Loops are counted once each, just as they are counted once in [`num_edges`](Self::num_edges).
+    fn num_loops(&self) -> I {
+        let loops = I::new(0)
+            .range(self.num_vertices())
+            .map(|v| {
+                self.neighbors(v, Direction::OUT)
+                    .filter(|&w| w == v)
+                    .count()
+            })
+            .sum();
+        I::new(loops)
+    }
+}
+
+/// A directed graph that supports inserting and removing edges.
+pub trait MutableDirectedGraph<I: Index>: DirectedGraph<I> {
+    /// Adds the edge `(u, v)`; parallel edges and loops are allowed.
+    fn add_edge(&mut self, u: I, v: I);
+
+    /// Removes one edge `(u, v)` and returns whether there was such an edge.
+    fn remove_edge(&mut self, u: I, v: I) -> bool;
+}
+
+/// Write the vertex and edge count followed by one line per vertex listing its out-adjacencies.
+fn fmt_adjacencies<I: Index, G: DirectedGraph<I>>(
+    graph: &G,
+    f: &mut fmt::Formatter<'_>,
+) -> fmt::Result {
+    write!(
+        f,
+        "{} vertices, {} edges",
+        graph.num_vertices(),
+        graph.num_edges()
+    )?;
+    for v in I::new(0).range(graph.num_vertices()) {
+        write!(f, "\n{}:", v)?;
+        for w in graph.neighbors(v, Direction::OUT) {
+            write!(f, " {}", w)?;
+        }
+    }
+    Ok(())
 }
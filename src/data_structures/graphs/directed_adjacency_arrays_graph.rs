@@ -1,4 +1,8 @@
-use serde::{Deserialize, Serialize};
+use std::{fmt, path::Path};
+
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+
+use crate::io::{self, IOError};
 
 use super::{directed_edge_list_graph::DirectedEdgeListGraph, DirectedGraph, Direction, Index};
 
@@ -8,6 +12,7 @@ use super::{directed_edge_list_graph::DirectedEdgeListGraph, DirectedGraph, Dire
 /// All out-adjacencies are stored in a single array, sorted by the source vertex.
 /// For each source vertex we store the offset of the first adjacency in the combined array. The end is derived by the offset of the next vertex or the end of the adjacency array.
 /// The same is stored for in-adjacencies.
+/// We additionally keep track of whether the out-adjacencies of every vertex are sorted, which allows for faster edge lookups.
 ///
 /// \[1\] F. Kammer and A. Sajenko, “Linear-Time In-Place DFS and BFS on the Word RAM,” in Algorithms and Complexity, P. Heggernes, Ed., in Lecture Notes in Computer Science. Cham: Springer International Publishing, 2019, pp. 286–298. doi: [10.1007/978-3-030-17402-6_24](https://doi.org/10.1007/978-3-030-17402-6_24).
 #[derive(Serialize, Deserialize, Debug)]
@@ -16,6 +21,9 @@ pub struct DirectedAdjacencyArraysGraph<I: Index> {
     out_adjacencies: Box<[I]>,
     in_offsets: Box<[I]>,
     in_adjacencies: Box<[I]>,
+    /// Whether the out-adjacencies of every vertex are sorted
+    #[serde(default)]
+    out_adjacencies_sorted: bool,
 }
 
 impl<I: Index> DirectedGraph<I> for DirectedAdjacencyArraysGraph<I> {
@@ -33,16 +41,76 @@ impl<I: Index> DirectedGraph<I> for DirectedAdjacencyArraysGraph<I> {
     }
 
     fn neighbors(&self, v: I, dir: Direction) -> Box<dyn Iterator<Item = I> + '_> {
-        let (start_inclusive, end_exclusive) = self.bounds(v, dir);
-        Box::new(
-            self.adjacencies(dir)[start_inclusive.index()..end_exclusive.index()]
-                .iter()
-                .copied(),
-        )
+        Box::new(self.adjacency_slice(v, dir).iter().copied())
+    }
+
+    /// Binary searches the out-adjacencies of `u` if they are sorted and scans them otherwise.
+    fn has_edge(&self, u: I, v: I) -> bool {
+        let successors = self.out_adjacency_slice(u);
+        if self.out_adjacencies_sorted {
+            successors.binary_search(&v).is_ok()
+        } else {
+            successors.contains(&v)
+        }
+    }
+}
+
+impl<I: Index> fmt::Display for DirectedAdjacencyArraysGraph<I> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        super::fmt_adjacencies(self, f)
+    }
+}
+
+impl<I: Index + Serialize + DeserializeOwned> DirectedAdjacencyArraysGraph<I> {
+    /// Serialize the graph to json and write it to the given file.
+    pub fn save_json(&self, file_path: &Path) -> Result<(), IOError> {
+        io::write_json_to_file(file_path, self)
+    }
+
+    /// Read a graph from the given json file.
+    pub fn load_json(file_path: &Path) -> Result<Self, IOError> {
+        io::read_json_from_file(file_path)
     }
 }
 
 impl<I: Index> DirectedAdjacencyArraysGraph<I> {
+    /// Returns the successors of `v` as contiguous slice.
+    #[inline]
+    pub fn out_adjacency_slice(&self, v: I) -> &[I] {
+        self.adjacency_slice(v, Direction::OUT)
+    }
+
+    /// Returns the predecessors of `v` as contiguous slice.
+    #[inline]
+    pub fn in_adjacency_slice(&self, v: I) -> &[I] {
+        self.adjacency_slice(v, Direction::IN)
+    }
+
+    /// Returns the graph with every edge reversed; loops and parallel edges are kept.
+    pub fn transpose(&self) -> Self {
+        let mut graph = Self {
+            out_offsets: self.in_offsets.clone(),
+            out_adjacencies: self.in_adjacencies.clone(),
+            in_offsets: self.out_offsets.clone(),
+            in_adjacencies: self.out_adjacencies.clone(),
+            out_adjacencies_sorted: false,
+        };
+        graph.out_adjacencies_sorted = graph.compute_out_adjacencies_sorted();
+        graph
+    }
+
+    fn compute_out_adjacencies_sorted(&self) -> bool {
+        I::new(0)
+            .range(self.num_vertices())
+            .all(|v| self.out_adjacency_slice(v).is_sorted())
+    }
+
+    #[inline]
+    fn adjacency_slice(&self, v: I, dir: Direction) -> &[I] {
+        let (start_inclusive, end_exclusive) = self.bounds(v, dir);
+        &self.adjacencies(dir)[start_inclusive.index()..end_exclusive.index()]
+    }
+
     #[inline]
     fn offsets(&self, dir: Direction) -> &[I] {
         match dir {
@@ -77,12 +145,15 @@ impl<I: Index> From<&DirectedEdgeListGraph<I>> for DirectedAdjacencyArraysGraph<
         let out_aa = AdjacencyArray::from_edges(el_graph, Direction::OUT);
         let in_aa = AdjacencyArray::from_edges(el_graph, Direction::IN);
 
-        Self {
+        let mut graph = Self {
             out_offsets: out_aa.offsets,
             out_adjacencies: out_aa.adjacencies,
             in_offsets: in_aa.offsets,
             in_adjacencies: in_aa.adjacencies,
-        }
+            out_adjacencies_sorted: false,
+        };
+        graph.out_adjacencies_sorted = graph.compute_out_adjacencies_sorted();
+        graph
     }
 }
 
@@ -107,9 +178,11 @@ impl<I: Index> AdjacencyArray<I> {
             offsets[vertex.index()] += I::new(1);
         }
 
-        // reset offsets
-        offsets.rotate_right(1);
-        offsets[0] = I::new(0);
+        // reset offsets; there are none for graphs without vertices
+        if !offsets.is_empty() {
+            offsets.rotate_right(1);
+            offsets[0] = I::new(0);
+        }
 
         AdjacencyArray {
             offsets,
@@ -127,3 +200,144 @@ fn degrees_to_offsets<I: Index>(mut degrees: Box<[I]>) -> Box<[I]> {
     }
     degrees
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const EDGES: [(u32, u32); 8] = [
+        (0, 1),
+        (0, 3),
+        (1, 4),
+        (2, 4),
+        (2, 5),
+        (3, 1),
+        (4, 3),
+        (5, 5),
+    ];
+
+    #[test]
+    fn test_json_round_trip() {
+        let graph = DirectedEdgeListGraph::new(6, Box::new(EDGES));
+        let graph = DirectedAdjacencyArraysGraph::from(&graph);
+
+        let file_path = std::env::temp_dir().join("exp_lib_test_json_round_trip.json");
+        graph.save_json(&file_path).unwrap();
+        let loaded = DirectedAdjacencyArraysGraph::<u32>::load_json(&file_path).unwrap();
+        std::fs::remove_file(&file_path).unwrap();
+
+        assert_eq!(loaded.num_vertices(), graph.num_vertices());
+        assert_eq!(loaded.num_edges(), graph.num_edges());
+        for v in 0..graph.num_vertices() {
+            for dir in [Direction::OUT, Direction::IN] {
+                assert!(loaded.neighbors(v, dir).eq(graph.neighbors(v, dir)));
+            }
+        }
+    }
+
+    #[test]
+    fn test_display() {
+        let graph = DirectedEdgeListGraph::new(6, Box::new(EDGES));
+        let graph = DirectedAdjacencyArraysGraph::from(&graph);
+
+        let display = format!("{}", graph);
+        assert!(display.starts_with("6 vertices, 8 edges"));
+        assert!(display.contains("\n0: 1 3\n"));
+        assert!(display.ends_with("\n5: 5"));
+    }
+
+    #[test]
+    fn test_num_loops() {
+        let graph = DirectedEdgeListGraph::new(6, Box::new(EDGES));
+        let graph = DirectedAdjacencyArraysGraph::from(&graph);
+
+        assert_eq!(graph.num_loops(), 1);
+        assert_eq!(graph.num_edges(), 8);
+        assert_eq!(
+            (0..6).map(|v| graph.degree(v, Direction::OUT)).sum::<u32>(),
+            graph.num_edges()
+        );
+    }
+
+    #[test]
+    fn test_has_edge() {
+        let graph = DirectedEdgeListGraph::new(6, Box::new(EDGES));
+        let graph = DirectedAdjacencyArraysGraph::from(&graph);
+
+        assert!(graph.out_adjacencies_sorted);
+        for u in 0..6 {
+            for v in 0..6 {
+                assert_eq!(graph.has_edge(u, v), EDGES.contains(&(u, v)));
+            }
+        }
+    }
+
+    #[test]
+    fn test_has_edge_unsorted() {
+        let edges = [(0, 3), (0, 1), (2, 0), (0, 2)];
+        let graph = DirectedEdgeListGraph::new(4, Box::new(edges));
+        let graph = DirectedAdjacencyArraysGraph::from(&graph);
+
+        assert!(!graph.out_adjacencies_sorted);
+        for u in 0..4 {
+            for v in 0..4 {
+                assert_eq!(graph.has_edge(u, v), edges.contains(&(u, v)));
+            }
+        }
+    }
+
+    #[test]
+    fn test_transpose() {
+        let graph = DirectedEdgeListGraph::new(6, Box::new(EDGES));
+        let graph = DirectedAdjacencyArraysGraph::from(&graph);
+        let transposed = graph.transpose();
+
+        assert_eq!(transposed.num_vertices(), 6);
+        assert_eq!(transposed.num_edges(), 8);
+        assert_eq!(transposed.num_loops(), 1);
+        for v in 0..6 {
+            assert_eq!(
+                transposed.out_adjacency_slice(v),
+                graph.in_adjacency_slice(v)
+            );
+            assert_eq!(
+                transposed.in_adjacency_slice(v),
+                graph.out_adjacency_slice(v)
+            );
+        }
+        for u in 0..6 {
+            for v in 0..6 {
+                assert_eq!(transposed.has_edge(v, u), EDGES.contains(&(u, v)));
+            }
+        }
+
+        let twice = transposed.transpose();
+        assert!(twice.out_adjacencies_sorted);
+        for v in 0..6 {
+            assert_eq!(twice.out_adjacency_slice(v), graph.out_adjacency_slice(v));
+            assert_eq!(twice.in_adjacency_slice(v), graph.in_adjacency_slice(v));
+        }
+    }
+
+    #[test]
+    fn test_empty_graph() {
+        let graph = DirectedEdgeListGraph::<u32>::new(0, Box::new([]));
+        let graph = DirectedAdjacencyArraysGraph::from(&graph);
+
+        assert_eq!(graph.num_vertices(), 0);
+        assert_eq!(graph.num_edges(), 0);
+    }
+
+    #[test]
+    fn test_adjacency_slices() {
+        let graph = DirectedEdgeListGraph::new(6, Box::new(EDGES));
+        let graph = DirectedAdjacencyArraysGraph::from(&graph);
+
+        let out_adjacencies: [&[u32]; 6] = [&[1, 3], &[4], &[4, 5], &[1], &[3], &[5]];
+        let in_adjacencies: [&[u32]; 6] = [&[], &[0, 3], &[], &[0, 4], &[1, 2], &[2, 5]];
+        for v in 0..6 {
+            assert_eq!(graph.out_adjacency_slice(v), out_adjacencies[v as usize]);
+            assert_eq!(graph.in_adjacency_slice(v), in_adjacencies[v as usize]);
+        }
+    }
+}
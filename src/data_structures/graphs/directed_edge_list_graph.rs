@@ -1,10 +1,12 @@
-use super::{DirectedGraph, Direction, Index};
+use std::fmt;
+
+use super::{DirectedGraph, Direction, Index, MutableDirectedGraph};
 
 /// A directed graph stored as number of vertices and list of edges.
 #[derive(Clone, Debug)]
 pub struct DirectedEdgeListGraph<I: Index> {
     num_vertices: I,
-    edges: Box<[(I, I)]>,
+    edges: Vec<(I, I)>,
 }
 
 impl<I: Index> DirectedGraph<I> for DirectedEdgeListGraph<I> {
@@ -30,19 +32,59 @@ impl<I: Index> DirectedGraph<I> for DirectedEdgeListGraph<I> {
             self.edges
                 .iter()
                 .filter(move |e| dir.vertex(e) == v)
-                .map(|e| e.1),
+                .map(move |e| dir.other(e)),
         )
     }
 }
 
+impl<I: Index> MutableDirectedGraph<I> for DirectedEdgeListGraph<I> {
+    /// Appends the edge to the edge list.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `u` or `v` is not a vertex of the graph.
+    fn add_edge(&mut self, u: I, v: I) {
+        assert!(
+            u < self.num_vertices && v < self.num_vertices,
+            "Edge ({}, {}) has an endpoint that is not a vertex.",
+            u,
+            v
+        );
+        self.edges.push((u, v));
+    }
+
+    /// Removes the first matching edge from the edge list, keeping the order of the remaining edges.
+    fn remove_edge(&mut self, u: I, v: I) -> bool {
+        match self.edges.iter().position(|&e| e == (u, v)) {
+            Some(i) => {
+                self.edges.remove(i);
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+impl<I: Index> fmt::Display for DirectedEdgeListGraph<I> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        super::fmt_adjacencies(self, f)
+    }
+}
+
 impl<I: Index> DirectedEdgeListGraph<I> {
     pub fn new(num_vertices: I, edges: Box<[(I, I)]>) -> Self {
         Self {
             num_vertices,
-            edges,
+            edges: edges.into_vec(),
         }
     }
 
+    /// Creates a graph that stores its edges sorted by `(source, sink)` instead of in the given order.
+    pub fn new_sorted(num_vertices: I, mut edges: Box<[(I, I)]>) -> Self {
+        edges.sort_unstable();
+        Self::new(num_vertices, edges)
+    }
+
     pub fn degrees(&self, dir: Direction) -> Box<[I]> {
         let mut degrees = vec![I::new(0); self.num_vertices.index()].into_boxed_slice();
         for edge in self.edges.iter() {
@@ -54,4 +96,118 @@ impl<I: Index> DirectedEdgeListGraph<I> {
     pub fn edges(&self) -> &[(I, I)] {
         &self.edges
     }
+
+    /// Returns the graph with every edge reversed, keeping the order of the edges.
+    pub fn transpose(&self) -> Self {
+        Self {
+            num_vertices: self.num_vertices,
+            edges: self.edges.iter().map(|&(u, v)| (v, u)).collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_display() {
+        let graph = DirectedEdgeListGraph::new(4, Box::new([(0, 2), (2, 1), (0, 1)]));
+
+        let display = format!("{}", graph);
+        assert!(display.starts_with("4 vertices, 3 edges"));
+        assert!(display.contains("\n0: 2 1\n"));
+        assert!(display.ends_with("\n3:"));
+    }
+
+    #[test]
+    fn test_num_loops() {
+        let graph = DirectedEdgeListGraph::new(3, Box::new([(0, 0), (0, 1), (2, 2), (1, 0)]));
+        assert_eq!(graph.num_loops(), 2);
+        assert_eq!(graph.num_edges(), 4);
+
+        let graph = DirectedEdgeListGraph::new(4, Box::new([(0, 2), (2, 1), (0, 1)]));
+        assert_eq!(graph.num_loops(), 0);
+    }
+
+    #[test]
+    fn test_add_parallel_edge() {
+        let mut graph = DirectedEdgeListGraph::new(3, Box::new([(0, 1), (1, 2)]));
+        graph.add_edge(0, 1);
+        graph.add_edge(2, 2);
+
+        assert_eq!(graph.num_edges(), 4);
+        assert_eq!(graph.num_loops(), 1);
+        assert_eq!(graph.degree(0, Direction::OUT), 2);
+        assert_eq!(graph.degree(1, Direction::IN), 2);
+        assert_eq!(graph.edges(), [(0, 1), (1, 2), (0, 1), (2, 2)]);
+    }
+
+    #[test]
+    fn test_remove_one_of_parallel_edges() {
+        let mut graph = DirectedEdgeListGraph::new(3, Box::new([(0, 1), (1, 2), (0, 1), (2, 2)]));
+
+        assert!(graph.remove_edge(0, 1));
+        assert_eq!(graph.num_edges(), 3);
+        assert_eq!(graph.degree(0, Direction::OUT), 1);
+        assert_eq!(graph.edges(), [(1, 2), (0, 1), (2, 2)]);
+
+        assert!(graph.remove_edge(2, 2));
+        assert_eq!(graph.num_loops(), 0);
+        assert!(!graph.remove_edge(2, 2));
+        assert!(!graph.remove_edge(1, 0));
+        assert_eq!(graph.num_edges(), 2);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_add_edge_out_of_range() {
+        let mut graph = DirectedEdgeListGraph::new(3, Box::new([(0, 1)]));
+        graph.add_edge(1, 3);
+    }
+
+    #[test]
+    fn test_neighbors() {
+        let graph = DirectedEdgeListGraph::new(3, Box::new([(0, 1), (2, 1), (1, 1), (1, 0)]));
+
+        assert!(graph.neighbors(1, Direction::OUT).eq([1, 0]));
+        assert!(graph.neighbors(1, Direction::IN).eq([0, 2, 1]));
+        assert_eq!(graph.neighbors(2, Direction::IN).count(), 0);
+    }
+
+    #[test]
+    fn test_transpose() {
+        let edges = [(2, 1), (0, 2), (1, 1), (0, 1), (2, 0), (0, 2)];
+        let graph = DirectedEdgeListGraph::new(3, Box::new(edges));
+        let transposed = graph.transpose();
+
+        assert_eq!(
+            transposed.edges(),
+            [(1, 2), (2, 0), (1, 1), (1, 0), (0, 2), (2, 0)]
+        );
+        assert_eq!(transposed.num_loops(), 1);
+        for v in 0..3 {
+            assert!(transposed
+                .neighbors(v, Direction::OUT)
+                .eq(graph.neighbors(v, Direction::IN)));
+        }
+        assert_eq!(transposed.transpose().edges(), graph.edges());
+    }
+
+    #[test]
+    fn test_new_sorted() {
+        let edges = [(2, 1), (0, 2), (1, 1), (0, 1), (2, 0), (0, 2)];
+        let graph = DirectedEdgeListGraph::new(3, Box::new(edges));
+        let sorted = DirectedEdgeListGraph::new_sorted(3, Box::new(edges));
+
+        assert_eq!(graph.edges(), edges);
+        assert_eq!(
+            sorted.edges(),
+            [(0, 1), (0, 2), (0, 2), (1, 1), (2, 0), (2, 1)]
+        );
+
+        let mut edge_set = graph.edges().to_vec();
+        edge_set.sort_unstable();
+        assert_eq!(edge_set, sorted.edges());
+    }
 }
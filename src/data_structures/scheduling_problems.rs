@@ -10,7 +10,7 @@ use super::graphs::Index;
 /// An instance consists of a configuration of machines, jobs and (optional)
 /// precedence constraints.
 /// Details of the environment, operation types, potential deadlines / release
-/// times and precedence constraints are left to the respective generic types.
+/// times, precedence constraints and job weights are left to the respective generic types.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SchedulingInstance<
     Machines: MachineEnvironment,
@@ -18,13 +18,15 @@ pub struct SchedulingInstance<
     Deadline = (),
     ReleaseTime = (),
     Precedences = (),
+    Weight = (),
 > where
     Operation: Default,
     Deadline: Default,
     ReleaseTime: Default,
+    Weight: Default,
 {
     pub environment: Machines,
-    pub jobs: Vec<Job<Operation, Deadline, ReleaseTime>>,
+    pub jobs: Vec<Job<Operation, Deadline, ReleaseTime, Weight>>,
     pub precedences: Precedences,
 }
 
@@ -34,24 +36,31 @@ pub struct SchedulingInstance<
 /// Each operation should have a processing time; the exact details are left to
 /// the generic `Operation` type.
 ///
+/// Jobs are unweighted by default (`Weight = ()`); weighted objectives like
+/// the total weighted completion time use a numeric `Weight` instead.
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct Job<Operation, Deadline = (), ReleaseTime = ()>
+pub struct Job<Operation, Deadline = (), ReleaseTime = (), Weight = ()>
 where
     Operation: Default,
     Deadline: Default,
     ReleaseTime: Default,
+    Weight: Default,
 {
     pub id: u32,
     pub operations: Vec<Operation>,
     pub deadline: Deadline,
     pub release_time: ReleaseTime,
+    // instances cached before jobs had weights don't contain this field
+    #[serde(default)]
+    pub weight: Weight,
 }
 
-impl<Operation, Deadline, ReleaseTime> Job<Operation, Deadline, ReleaseTime>
+impl<Operation, Deadline, ReleaseTime, Weight> Job<Operation, Deadline, ReleaseTime, Weight>
 where
     Operation: Default,
     Deadline: Default,
     ReleaseTime: Default,
+    Weight: Default,
 {
     pub fn for_num_operations(id: u32, num_operations: u32) -> Self
     where
@@ -62,6 +71,78 @@ where
             operations: vec![Operation::default(); num_operations.index()],
             deadline: Deadline::default(),
             release_time: ReleaseTime::default(),
+            weight: Weight::default(),
+        }
+    }
+}
+
+impl<Operation, Deadline, ReleaseTime> Job<Operation, Deadline, ReleaseTime>
+where
+    Operation: Default,
+    Deadline: Default,
+    ReleaseTime: Default,
+{
+    /// Creates an unweighted job.
+    pub fn new(
+        id: u32,
+        operations: Vec<Operation>,
+        deadline: Deadline,
+        release_time: ReleaseTime,
+    ) -> Self {
+        Self {
+            id,
+            operations,
+            deadline,
+            release_time,
+            weight: (),
+        }
+    }
+
+    /// Turns the unweighted job into a job with the given weight.
+    pub fn with_weight<Weight: Default>(
+        self,
+        weight: Weight,
+    ) -> Job<Operation, Deadline, ReleaseTime, Weight> {
+        Job {
+            id: self.id,
+            operations: self.operations,
+            deadline: self.deadline,
+            release_time: self.release_time,
+            weight,
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_weighted_instance() {
+        let instance: SchedulingInstance<SingleMachine, i32, (), (), (), u32> =
+            SchedulingInstance {
+                environment: SingleMachine,
+                jobs: [(0, 3, 2), (1, 5, 7)]
+                    .iter()
+                    .map(|j| Job::new(j.0, vec![j.1], (), ()).with_weight(j.2))
+                    .collect(),
+                precedences: (),
+            };
+
+        assert_eq!(instance.jobs[0].weight, 2);
+        assert_eq!(instance.jobs[1].weight, 7);
+        assert_eq!(instance.jobs[1].operations, [5]);
+    }
+
+    #[test]
+    fn test_unweighted_job_from_json() {
+        // jobs serialized before the introduction of weights
+        let job: Job<i32> = serde_json::from_str(
+            r#"{"id":3,"operations":[42],"deadline":null,"release_time":null}"#,
+        )
+        .unwrap();
+
+        assert_eq!(job.id, 3);
+        assert_eq!(job.weight, ());
+    }
+}
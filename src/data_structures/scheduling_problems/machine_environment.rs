@@ -1,19 +1,65 @@
 use serde::{Deserialize, Serialize};
 
 /// Marker trait for machine environments
-pub trait MachineEnvironment: std::fmt::Debug {}
+pub trait MachineEnvironment: std::fmt::Debug {
+    /// Number of machines, numbered from 1
+    fn machines(&self) -> u32;
+
+    /// How the operations of a job relate to the machines
+    fn operation_machines(&self) -> OperationMachines {
+        OperationMachines::AnyMachine
+    }
+}
+
+/// How the operations of a job relate to the machines of an environment
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OperationMachines {
+    /// Each job has a single operation that is processed on one of the machines.
+    AnyMachine,
+    /// Operation i is the processing time on machine i + 1; each job is processed on one of the machines.
+    OneOfMachines,
+    /// Operation i is processed on machine i + 1; each job is processed on every machine.
+    EveryMachine,
+}
 
 /// Machine environment: Single machine
 #[derive(Debug, Serialize, Deserialize)]
 pub struct SingleMachine;
-impl MachineEnvironment for SingleMachine {}
+impl MachineEnvironment for SingleMachine {
+    fn machines(&self) -> u32 {
+        1
+    }
+}
 
 /// Machine environment: Identical parallel machines
 #[derive(Debug, Serialize, Deserialize)]
 pub struct ParallelMachines {
     pub machines: u32,
 }
-impl MachineEnvironment for ParallelMachines {}
+impl MachineEnvironment for ParallelMachines {
+    fn machines(&self) -> u32 {
+        self.machines
+    }
+}
+
+/// Machine environment: Unrelated parallel machines
+///
+/// Each job consists of m operations, one for each machine, that give the
+/// processing time of the job if it is processed on that machine.
+/// Each job is processed on exactly one of the machines.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct UnrelatedMachines {
+    pub machines: u32,
+}
+impl MachineEnvironment for UnrelatedMachines {
+    fn machines(&self) -> u32 {
+        self.machines
+    }
+
+    fn operation_machines(&self) -> OperationMachines {
+        OperationMachines::OneOfMachines
+    }
+}
 
 /// Machine environment: Flow Shop
 ///
@@ -23,7 +69,15 @@ impl MachineEnvironment for ParallelMachines {}
 pub struct FlowShop {
     pub machines: u32,
 }
-impl MachineEnvironment for FlowShop {}
+impl MachineEnvironment for FlowShop {
+    fn machines(&self) -> u32 {
+        self.machines
+    }
+
+    fn operation_machines(&self) -> OperationMachines {
+        OperationMachines::EveryMachine
+    }
+}
 
 /// Machine environment: Open Shop
 ///
@@ -33,7 +87,15 @@ impl MachineEnvironment for FlowShop {}
 pub struct OpenShop {
     pub machines: u32,
 }
-impl MachineEnvironment for OpenShop {}
+impl MachineEnvironment for OpenShop {
+    fn machines(&self) -> u32 {
+        self.machines
+    }
+
+    fn operation_machines(&self) -> OperationMachines {
+        OperationMachines::EveryMachine
+    }
+}
 
 /// Machine environment: Job Shop
 ///
@@ -44,4 +106,8 @@ impl MachineEnvironment for OpenShop {}
 pub struct JobShop {
     pub machines: u32,
 }
-impl MachineEnvironment for JobShop {}
+impl MachineEnvironment for JobShop {
+    fn machines(&self) -> u32 {
+        self.machines
+    }
+}
@@ -5,11 +5,11 @@ use std::{
 };
 
 use num::{traits::AsPrimitive, Bounded, Float};
-use serde::{de::DeserializeOwned, Deserialize, Serialize, Serializer};
+use serde::{de::DeserializeOwned, ser::SerializeStruct, Deserialize, Serialize, Serializer};
 
 use crate::{
     experiments::{EnumerationMeasurement, TotalTimeMeasurement},
-    io::{self, IOError},
+    io::{self, CsvOptions, HeaderMode, IOError, MeasurementFormat},
 };
 
 use super::{ExperimentAlgorithm, ExperimentGenerator};
@@ -43,7 +43,9 @@ impl_forward_min_max!(Float, f32 f64);
 pub trait Aggregatable: Copy + AsPrimitive<f64> + Bounded + MinMax {}
 impl<T: Copy + AsPrimitive<f64> + Bounded + MinMax> Aggregatable for T {}
 
-/// Aggregate data points by count, minimum, maximum and average
+/// Aggregate data points by count, minimum, maximum, average and variance
+///
+/// Average and variance are maintained in a single pass with Welford's algorithm.
 #[derive(Serialize, Deserialize)]
 pub struct Aggregation<T: Aggregatable = u32> {
     /// The number of aggregated data points
@@ -54,6 +56,9 @@ pub struct Aggregation<T: Aggregatable = u32> {
     pub max: T,
     /// The average of all observed data points
     pub avg: f64,
+    /// The sum of squared differences from the average of all observed data points
+    #[serde(default)]
+    pub m2: f64,
 }
 
 impl<T: Aggregatable> Aggregation<T> {
@@ -66,6 +71,7 @@ impl<T: Aggregatable> Aggregation<T> {
             min: T::max_value(),
             max: T::min_value(),
             avg: 0.0,
+            m2: 0.0,
         }
     }
 
@@ -76,15 +82,28 @@ impl<T: Aggregatable> Aggregation<T> {
         self.max = self.max.max(value);
         self.min = self.min.min(value);
 
+        let value: f64 = value.as_();
         if self.n == 1 {
-            self.avg = value.as_();
+            self.avg = value;
         } else {
-            self.avg += (value.as_() - self.avg) / self.n as f64;
+            let delta = value - self.avg;
+            self.avg += delta / self.n as f64;
+            self.m2 += delta * (value - self.avg);
         };
     }
 
-    fn serialize_to_avg<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
-        serializer.serialize_f64(self.avg)
+    /// The sample variance of all observed data points; 0 for less than two data points
+    pub fn variance(&self) -> f64 {
+        if self.n < 2 {
+            0.0
+        } else {
+            self.m2 / (self.n - 1) as f64
+        }
+    }
+
+    /// The sample standard deviation of all observed data points; 0 for less than two data points
+    pub fn std_dev(&self) -> f64 {
+        self.variance().sqrt()
     }
 }
 
@@ -94,45 +113,124 @@ impl<T: Aggregatable> Default for Aggregation<T> {
     }
 }
 
-#[derive(Serialize, Default)]
+/// Aggregate data points like [Aggregation] and additionally remember which
+/// data points (e.g. which instances) produced the minimum and maximum value
+#[derive(Serialize, Deserialize)]
+pub struct ExtremesAggregation<T: Aggregatable = u32, Id = String> {
+    /// The aggregation of all data points
+    pub aggregation: Aggregation<T>,
+    /// The identifier of the first data point with the smallest value
+    pub argmin: Option<Id>,
+    /// The identifier of the first data point with the largest value
+    pub argmax: Option<Id>,
+}
+
+impl<T: Aggregatable + PartialOrd, Id: Clone> ExtremesAggregation<T, Id> {
+    /// Create an empty aggregation
+    pub fn new() -> Self {
+        Self {
+            aggregation: Aggregation::new(),
+            argmin: None,
+            argmax: None,
+        }
+    }
+
+    /// Push a new data point identified by `id` to the aggregation
+    pub fn push(&mut self, value: T, id: &Id) {
+        if self.argmin.is_none() || value < self.aggregation.min {
+            self.argmin = Some(id.clone());
+        }
+        if self.argmax.is_none() || value > self.aggregation.max {
+            self.argmax = Some(id.clone());
+        }
+        self.aggregation.push(value);
+    }
+}
+
+impl<T: Aggregatable + PartialOrd, Id: Clone> Default for ExtremesAggregation<T, Id> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Serialized as the averages of all aggregations, plus the standard deviation of the total time
+/// (`total_time_std`) right after its average, and the number of failed runs last.
+#[derive(Default)]
 pub struct TotalTimeAggregation {
     /// The instance size
     pub size: u32,
     /// The total computation time in ns
-    #[serde(serialize_with = "Aggregation::serialize_to_avg")]
     pub total_time: Aggregation<u64>,
+    /// The preprocessing time in ns as reported by the algorithm
+    pub preprocessing: Aggregation<u64>,
+    /// The number of runs in which the algorithm could not compute a solution
+    pub failures: u32,
+}
+
+impl Serialize for TotalTimeAggregation {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut row = serializer.serialize_struct("TotalTimeAggregation", 5)?;
+        row.serialize_field("size", &self.size)?;
+        row.serialize_field("total_time", &self.total_time.avg)?;
+        row.serialize_field("total_time_std", &self.total_time.std_dev())?;
+        row.serialize_field("preprocessing", &self.preprocessing.avg)?;
+        row.serialize_field("failures", &self.failures)?;
+        row.end()
+    }
 }
 
-#[derive(Serialize, Default)]
+/// Serialized as the averages of all aggregations, plus the standard deviations of the total time
+/// (`total_time_std`) and the average delay (`delay_avg_std`) right after their averages,
+/// and the number of failed runs last.
+/// `delay_variance` is the average of the per-run delay variances.
+#[derive(Default)]
 pub struct EnumerationAggregation {
     /// The instance size
     pub size: u32,
     /// The total time in ns
-    #[serde(serialize_with = "Aggregation::serialize_to_avg")]
     pub total_time: Aggregation<u64>,
     /// The preprocessing time in ns
-    #[serde(serialize_with = "Aggregation::serialize_to_avg")]
     pub preprocessing: Aggregation<u64>,
     /// The time-to-first-output in ns
-    #[serde(serialize_with = "Aggregation::serialize_to_avg")]
     pub first_output: Aggregation<u64>,
     /// The minimum delay time time in ns
-    #[serde(serialize_with = "Aggregation::serialize_to_avg")]
     pub delay_min: Aggregation<u64>,
     /// The maximum delay time in ns
-    #[serde(serialize_with = "Aggregation::serialize_to_avg")]
     pub delay_max: Aggregation<u64>,
-    /// The preprocessing time in ns
-    #[serde(serialize_with = "Aggregation::serialize_to_avg")]
+    /// The average delay time in ns
     pub delay_avg: Aggregation<f64>,
+    /// The sample variance of the delay times in ns²
+    pub delay_variance: Aggregation<f64>,
+    /// The number of runs in which the algorithm could not compute a solution
+    pub failures: u32,
+}
+
+impl Serialize for EnumerationAggregation {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut row = serializer.serialize_struct("EnumerationAggregation", 11)?;
+        row.serialize_field("size", &self.size)?;
+        row.serialize_field("total_time", &self.total_time.avg)?;
+        row.serialize_field("total_time_std", &self.total_time.std_dev())?;
+        row.serialize_field("preprocessing", &self.preprocessing.avg)?;
+        row.serialize_field("first_output", &self.first_output.avg)?;
+        row.serialize_field("delay_min", &self.delay_min.avg)?;
+        row.serialize_field("delay_max", &self.delay_max.avg)?;
+        row.serialize_field("delay_avg", &self.delay_avg.avg)?;
+        row.serialize_field("delay_avg_std", &self.delay_avg.std_dev())?;
+        row.serialize_field("delay_variance", &self.delay_variance.avg)?;
+        row.serialize_field("failures", &self.failures)?;
+        row.end()
+    }
 }
 
 /// Helper struct to parse a measurement path.
 ///
-/// A measurement path has the form './data/{type}/{subtype}/{size}_{parameter1[-parameter2[...]]}_{RNG state id}.{algo}.csv'.
+/// A measurement path has the form './data/{type}/{subtype}/{size}_{parameter1[-parameter2[...]]}_{RNG state id}.{algo}.{csv|jsonl}'.
 struct MeasurementFilePath {
     /// The complete path
     full_path: PathBuf,
+    /// the file format given by the extension
+    format: MeasurementFormat,
     /// the instance size
     size: u32,
     /// the parameters
@@ -144,11 +242,11 @@ impl MeasurementFilePath {
         let full_path = PathBuf::from(path);
 
         let mut editable_path = full_path.clone();
-        editable_path
+        let format = editable_path
             .extension()
             .and_then(OsStr::to_str)
-            .filter(|&e| e == "csv")?; // ensure extension is csv
-        editable_path.set_extension(""); // remove 'csv' extension
+            .and_then(MeasurementFormat::from_extension)?; // ensure extension is csv or jsonl
+        editable_path.set_extension(""); // remove 'csv' or 'jsonl' extension
 
         editable_path
             .extension()
@@ -161,29 +259,64 @@ impl MeasurementFilePath {
         // second part of stem are the parameters
         let parameters = String::from(parts.next()?);
         // third part of stem is the RNG state id that we ignore in aggregation
+        parts.next()?;
+        // anything else means the parameters contained an underscore and can't be told apart
+        if parts.next().is_some() {
+            return None;
+        }
 
         Some(Self {
             full_path,
+            format,
             size,
             parameters,
         })
     }
 }
 
-pub fn aggregate<Generator, Input, Partial, Output>(
-    algorithm: &ExperimentAlgorithm<Input, Partial, Output>,
+impl MeasurementFilePath {
+    /// Lazily read the measurements from the file according to its format.
+    fn stream<T: DeserializeOwned + 'static>(
+        &self,
+    ) -> Result<Box<dyn Iterator<Item = Result<T, IOError>>>, IOError> {
+        Ok(match self.format {
+            MeasurementFormat::Csv => Box::new(io::stream_csv_from_file(&self.full_path)?),
+            MeasurementFormat::JsonLines => {
+                Box::new(io::jsonl::stream_jsonl_from_file(&self.full_path)?)
+            }
+        })
+    }
+}
+
+pub fn aggregate<Generator, Input, Partial, Output, Scratch>(
+    algorithm: &ExperimentAlgorithm<Input, Partial, Output, Scratch>,
 ) -> Result<(), IOError>
 where
     Input: DeserializeOwned + Serialize,
     Generator: ExperimentGenerator<Input>,
 {
-    let folder = Generator::path();
-    let folder = Path::new(&folder);
+    aggregate_with_options::<Generator, _, _, _, _>(algorithm, &CsvOptions::default())
+}
 
-    let algorithm_name = match algorithm {
-        ExperimentAlgorithm::EnumerationAlgorithm(name, _) => *name,
-        ExperimentAlgorithm::TotalTimeAlgorithm(name, _) => *name,
-    };
+/// Aggregates like [`aggregate`], writing the aggregated csv files according to `options`.
+pub fn aggregate_with_options<Generator, Input, Partial, Output, Scratch>(
+    algorithm: &ExperimentAlgorithm<Input, Partial, Output, Scratch>,
+    options: &CsvOptions,
+) -> Result<(), IOError>
+where
+    Input: DeserializeOwned + Serialize,
+    Generator: ExperimentGenerator<Input>,
+{
+    aggregate_folder(Path::new(&Generator::path()), algorithm, options)
+}
+
+/// Aggregates the measurements of `algorithm` in `folder` like [`aggregate_with_options`].
+pub fn aggregate_folder<Input, Partial, Output, Scratch>(
+    folder: &Path,
+    algorithm: &ExperimentAlgorithm<Input, Partial, Output, Scratch>,
+    options: &CsvOptions,
+) -> Result<(), IOError> {
+    let algorithm_name = algorithm.name();
 
     let files = match folder.read_dir() {
         Err(why) => {
@@ -201,10 +334,12 @@ where
 
     match algorithm {
         ExperimentAlgorithm::EnumerationAlgorithm(algorithm_name, _) => {
-            aggregate_enumeration_algorithm(files, folder, algorithm_name)
+            aggregate_enumeration_algorithm(files, folder, algorithm_name, options)
         }
-        ExperimentAlgorithm::TotalTimeAlgorithm(algorithm_name, _) => {
-            aggregate_total_time_algorithm(files, folder, algorithm_name)
+        ExperimentAlgorithm::TotalTimeAlgorithm(algorithm_name, _)
+        | ExperimentAlgorithm::TotalTimeAlgorithmWithPreprocessing(algorithm_name, _)
+        | ExperimentAlgorithm::TotalTimeAlgorithmWithScratch(algorithm_name, _) => {
+            aggregate_total_time_algorithm(files, folder, algorithm_name, options)
         }
     }
 }
@@ -213,10 +348,11 @@ fn aggregate_enumeration_algorithm(
     files: impl Iterator<Item = MeasurementFilePath>,
     folder: &Path,
     algorithm_name: &str,
+    options: &CsvOptions,
 ) -> Result<(), IOError> {
     let mut aggregations_by_parameter = HashMap::new();
     files.for_each(|f| {
-        let measurements = io::read_csv_from_file::<EnumerationMeasurement>(&f.full_path);
+        let measurements = f.stream::<EnumerationMeasurement>();
         match measurements {
             Err(why) => {
                 log::info!("Could not read from {}: {}", f.full_path.display(), why)
@@ -230,13 +366,20 @@ fn aggregate_enumeration_algorithm(
                         size: f.size,
                         ..Default::default()
                     });
-                for m in measurements {
+                // skip rows that can't be deserialized
+                for m in measurements.filter_map(Result::ok) {
+                    // failed runs are counted, but don't distort the timings
+                    if m.failed {
+                        aggregation.failures += 1;
+                        continue;
+                    }
                     aggregation.total_time.push(m.total_time);
                     aggregation.preprocessing.push(m.preprocessing);
                     aggregation.first_output.push(m.first_output);
                     aggregation.delay_min.push(m.delay_min);
                     aggregation.delay_max.push(m.delay_max);
                     aggregation.delay_avg.push(m.delay_avg);
+                    aggregation.delay_variance.push(m.delay_variance);
                 }
             }
         }
@@ -246,7 +389,7 @@ fn aggregate_enumeration_algorithm(
         path.push(format!("aggregated_{}.{}.csv", parameters, algorithm_name));
         let mut values = aggregations_by_size.values().collect::<Vec<_>>();
         values.sort_unstable_by_key(|v| v.size);
-        io::append_csv_to_file(path.as_path(), &values)?;
+        io::append_csv_to_file_with_options(path.as_path(), &values, &HeaderMode::Auto, options)?;
     }
     Ok(())
 }
@@ -255,10 +398,11 @@ fn aggregate_total_time_algorithm(
     files: impl Iterator<Item = MeasurementFilePath>,
     folder: &Path,
     algorithm_name: &str,
+    options: &CsvOptions,
 ) -> Result<(), IOError> {
     let mut aggregations_by_parameter = HashMap::new();
     files.for_each(|f| {
-        let measurements = io::read_csv_from_file::<TotalTimeMeasurement>(&f.full_path);
+        let measurements = f.stream::<TotalTimeMeasurement>();
         match measurements {
             Err(why) => {
                 log::info!("Could not read from {}: {}", f.full_path.display(), why)
@@ -272,8 +416,15 @@ fn aggregate_total_time_algorithm(
                         size: f.size,
                         ..Default::default()
                     });
-                for m in measurements {
+                // skip rows that can't be deserialized
+                for m in measurements.filter_map(Result::ok) {
+                    // failed runs are counted, but don't distort the timings
+                    if m.failed {
+                        aggregation.failures += 1;
+                        continue;
+                    }
                     aggregation.total_time.push(m.total_time);
+                    aggregation.preprocessing.push(m.preprocessing);
                 }
             }
         }
@@ -283,11 +434,117 @@ fn aggregate_total_time_algorithm(
         path.push(format!("aggregated_{}.{}.csv", parameters, algorithm_name));
         let mut values = aggregations_by_size.values().collect::<Vec<_>>();
         values.sort_unstable_by_key(|v| v.size);
-        io::append_csv_to_file(path.as_path(), &values)?;
+        io::append_csv_to_file_with_options(path.as_path(), &values, &HeaderMode::Auto, options)?;
     }
     Ok(())
 }
 
+/// The best algorithm for one parameter setting and instance size.
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+pub struct SummaryRow {
+    /// The parameters
+    pub parameters: String,
+    /// The instance size
+    pub size: u32,
+    /// The name of the best algorithm
+    pub algorithm: String,
+    /// The (smallest) aggregated value of the metric
+    pub value: f64,
+}
+
+/// The best algorithm per parameter setting and instance size with respect to a single metric.
+#[derive(Debug, Default)]
+pub struct SummaryReport {
+    /// The metric, i.e. the name of the aggregated column that was compared
+    pub metric: String,
+    /// One row per parameter setting and size, ordered by parameters and size
+    pub rows: Vec<SummaryRow>,
+}
+
+/// Picks the best algorithm for every parameter setting and instance size from the aggregated csv files in `folder`.
+///
+/// The best algorithm is the one with the smallest value in the `metric` column (e.g. `"total_time"`).
+/// If an aggregated file has several rows for a size (from repeated aggregations), the last one is used.
+/// Algorithms that failed on any run of a size are not considered for that size.
+/// The report is also written to `summary.csv` in `folder`, replacing any earlier summary.
+pub fn summarize<Input, Partial, Output, Scratch>(
+    folder: &Path,
+    algorithms: &[ExperimentAlgorithm<Input, Partial, Output, Scratch>],
+    metric: &str,
+) -> Result<SummaryReport, IOError> {
+    let files: Vec<PathBuf> = match folder.read_dir() {
+        Err(why) => {
+            return Err(IOError::CannotRead(
+                folder.display().to_string(),
+                why.to_string(),
+            ))
+        }
+        Ok(files) => files.filter_map(Result::ok).map(|f| f.path()).collect(),
+    };
+
+    let mut best: HashMap<(String, u32), SummaryRow> = HashMap::new();
+    for algorithm in algorithms {
+        let suffix = format!(".{}.csv", algorithm.name());
+        for file in &files {
+            let Some(parameters) = file
+                .file_name()
+                .and_then(OsStr::to_str)
+                .and_then(|name| name.strip_prefix("aggregated_"))
+                .and_then(|name| name.strip_suffix(&suffix))
+            else {
+                continue;
+            };
+
+            let mut values_by_size = HashMap::new();
+            for row in
+                io::stream_csv_from_file::<HashMap<String, f64>>(file)?.filter_map(Result::ok)
+            {
+                if let (Some(&size), Some(&value)) = (row.get("size"), row.get(metric)) {
+                    let failed = row.get("failures").is_some_and(|&failures| failures > 0.0);
+                    values_by_size.insert(size as u32, (!failed).then_some(value));
+                }
+            }
+
+            for (size, value) in values_by_size {
+                let Some(value) = value else {
+                    continue;
+                };
+                let key = (String::from(parameters), size);
+                if best.get(&key).is_none_or(|row| value < row.value) {
+                    best.insert(
+                        key,
+                        SummaryRow {
+                            parameters: String::from(parameters),
+                            size,
+                            algorithm: String::from(algorithm.name()),
+                            value,
+                        },
+                    );
+                }
+            }
+        }
+    }
+
+    let mut rows: Vec<SummaryRow> = best.into_values().collect();
+    rows.sort_unstable_by(|a, b| (&a.parameters, a.size).cmp(&(&b.parameters, b.size)));
+
+    let summary_path = folder.join("summary.csv");
+    if let Err(why) = std::fs::remove_file(&summary_path) {
+        if why.kind() != std::io::ErrorKind::NotFound {
+            return Err(IOError::CannotWrite(
+                summary_path.display().to_string(),
+                why.to_string(),
+            ));
+        }
+    }
+    io::append_csv_to_file(&summary_path, &rows)?;
+
+    Ok(SummaryReport {
+        metric: String::from(metric),
+        rows,
+    })
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -296,7 +553,7 @@ mod test {
     fn test_measurement_aggregation() {
         let mut aggregation = Aggregation::new();
 
-        vec![1, 7, 6, 3, 4, 9, 0, 5, 8, 2]
+        [1, 7, 6, 3, 4, 9, 0, 5, 8, 2]
             .iter()
             .for_each(|x| aggregation.push(*x));
 
@@ -305,4 +562,170 @@ mod test {
         assert_eq!(aggregation.max, 9);
         assert_eq!(aggregation.avg, 4.5);
     }
+
+    #[test]
+    fn test_measurement_aggregation_variance() {
+        let data = [1, 7, 6, 3, 4, 9, 0, 5, 8, 2];
+        let mut aggregation = Aggregation::new();
+        data.iter().for_each(|x| aggregation.push(*x));
+
+        let avg = data.iter().sum::<u32>() as f64 / data.len() as f64;
+        let variance =
+            data.iter().map(|&x| (x as f64 - avg).powi(2)).sum::<f64>() / (data.len() - 1) as f64;
+
+        assert!((aggregation.variance() - variance).abs() < 1e-9);
+        assert!((aggregation.std_dev() - variance.sqrt()).abs() < 1e-9);
+
+        let mut single = Aggregation::new();
+        single.push(3u32);
+        assert_eq!(single.variance(), 0.0);
+    }
+
+    #[test]
+    fn test_measurement_file_path() {
+        let file_name = format!(
+            "100_{}_TLCG-42.enum-iqs.csv",
+            crate::experiments::sanitize_parameter("log_2(n)/n")
+        );
+        let path = MeasurementFilePath::try_new(Path::new(&file_name), "enum-iqs").unwrap();
+        assert_eq!(path.size, 100);
+        assert_eq!(path.parameters, "log-2(n)-n");
+        assert_eq!(path.format, MeasurementFormat::Csv);
+
+        let jsonl_path =
+            MeasurementFilePath::try_new(Path::new("100_x_TLCG-42.enum-iqs.jsonl"), "enum-iqs")
+                .unwrap();
+        assert_eq!(jsonl_path.size, 100);
+        assert_eq!(jsonl_path.format, MeasurementFormat::JsonLines);
+        assert!(
+            MeasurementFilePath::try_new(Path::new("100_x_TLCG-42.enum-iqs.json"), "enum-iqs")
+                .is_none()
+        );
+
+        assert!(MeasurementFilePath::try_new(Path::new(&file_name), "enum-other").is_none());
+        assert!(MeasurementFilePath::try_new(
+            Path::new("100_log_2(n)_TLCG-42.enum-iqs.csv"),
+            "enum-iqs"
+        )
+        .is_none());
+    }
+
+    #[test]
+    fn test_extremes_aggregation() {
+        let mut aggregation = ExtremesAggregation::new();
+
+        // pairs of (size, rng id) and measurement
+        [
+            ((100, "TLCG-1"), 17),
+            ((100, "TLCG-2"), 3),
+            ((200, "TLCG-3"), 42),
+            ((200, "TLCG-4"), 3),
+            ((300, "TLCG-5"), 42),
+            ((300, "TLCG-6"), 12),
+        ]
+        .iter()
+        .for_each(|(id, x)| aggregation.push(*x, id));
+
+        assert_eq!(aggregation.aggregation.n, 6);
+        assert_eq!(aggregation.aggregation.min, 3);
+        assert_eq!(aggregation.aggregation.max, 42);
+        assert_eq!(aggregation.argmin, Some((100, "TLCG-2")));
+        assert_eq!(aggregation.argmax, Some((200, "TLCG-3")));
+    }
+
+    #[derive(Serialize, Deserialize)]
+    struct AggregatedRow {
+        size: u32,
+        total_time: f64,
+        first_output: f64,
+    }
+
+    fn write_aggregated(
+        folder: &Path,
+        parameters: &str,
+        algorithm: &str,
+        rows: &[(u32, f64, f64)],
+    ) {
+        let rows: Vec<_> = rows
+            .iter()
+            .map(|&(size, total_time, first_output)| AggregatedRow {
+                size,
+                total_time,
+                first_output,
+            })
+            .collect();
+        let path = folder.join(format!("aggregated_{}.{}.csv", parameters, algorithm));
+        io::append_csv_to_file(&path, &rows).unwrap();
+    }
+
+    #[test]
+    fn test_summarize() {
+        let folder = std::env::temp_dir().join("exp_lib_test_summarize");
+        let _ = std::fs::remove_dir_all(&folder);
+
+        write_aggregated(&folder, "a", "fast", &[(10, 1.0, 0.5), (20, 5.0, 0.5)]);
+        write_aggregated(&folder, "a", "slow", &[(10, 2.0, 0.1), (20, 4.0, 0.1)]);
+        write_aggregated(&folder, "b", "fast", &[(10, 3.0, 0.5)]);
+        // unrelated algorithms are ignored
+        write_aggregated(&folder, "b", "other", &[(10, 0.0, 0.0)]);
+
+        let algorithms: [ExperimentAlgorithm<(), (), ()>; 2] = [
+            ExperimentAlgorithm::TotalTimeAlgorithm("fast", |_| ()),
+            ExperimentAlgorithm::TotalTimeAlgorithm("slow", |_| ()),
+        ];
+        let by_total_time = summarize(&folder, &algorithms, "total_time").unwrap();
+        let written: Vec<SummaryRow> = io::read_csv_from_file(&folder.join("summary.csv")).unwrap();
+        let by_first_output = summarize(&folder, &algorithms, "first_output").unwrap();
+        std::fs::remove_dir_all(&folder).unwrap();
+
+        let row = |parameters: &str, size, algorithm: &str, value| SummaryRow {
+            parameters: String::from(parameters),
+            size,
+            algorithm: String::from(algorithm),
+            value,
+        };
+        assert_eq!(
+            by_total_time.rows,
+            [
+                row("a", 10, "fast", 1.0),
+                row("a", 20, "slow", 4.0),
+                row("b", 10, "fast", 3.0)
+            ]
+        );
+        assert_eq!(written, by_total_time.rows);
+        assert_eq!(
+            by_first_output.rows,
+            [
+                row("a", 10, "slow", 0.1),
+                row("a", 20, "slow", 0.1),
+                row("b", 10, "fast", 0.5)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_aggregate_rejects_outdated_aggregated_file() {
+        let folder = std::env::temp_dir().join("exp_lib_test_aggregate_outdated");
+        let _ = std::fs::remove_dir_all(&folder);
+
+        // an aggregated file with columns that the aggregation no longer writes
+        write_aggregated(&folder, "a", "fast", &[(10, 1.0, 0.5)]);
+        let measurement_path = folder.join("10_a_TLCG-1.fast.csv");
+        let measurement = TotalTimeMeasurement {
+            total_time: 2,
+            preprocessing: 0,
+            peak_bytes: None,
+            failed: false,
+        };
+        io::append_csv_to_file(&measurement_path, &[measurement]).unwrap();
+
+        let files = MeasurementFilePath::try_new(&measurement_path, "fast").into_iter();
+        let result = aggregate_total_time_algorithm(files, &folder, "fast", &CsvOptions::default());
+        let aggregated: Vec<AggregatedRow> =
+            io::read_csv_from_file(&folder.join("aggregated_a.fast.csv")).unwrap();
+        std::fs::remove_dir_all(&folder).unwrap();
+
+        assert!(matches!(result, Err(IOError::CannotSerialize(_, _))));
+        assert_eq!(aggregated.len(), 1);
+    }
 }
@@ -9,21 +9,90 @@ use serde::{de::DeserializeOwned, Deserialize, Serialize};
 use crate::io;
 
 pub type TotalTimeAlgorithm<Input, Output> = fn(&Input) -> Output;
-pub type PreparedEnumerationAlgorithm<'a, Partial> = Box<dyn Iterator<Item = Partial> + 'a>;
+/// A total time algorithm that additionally reports the time in ns it spent on preprocessing
+/// (e.g. building indices) before computing the actual solution.
+pub type TotalTimeAlgorithmWithPreprocessing<Input, Output> = fn(&Input) -> (Output, u64);
+/// A total time algorithm that may reuse a scratch buffer across runs.
+///
+/// The scratch is created once per instance via `Default` (outside of the measured time) and handed to every run
+/// on that instance; it still contains whatever the previous run left in it, so the algorithm has to reset it as needed.
+/// Scratch is never shared between instances or used by two runs at once, so it doesn't need to be `Sync`.
+/// It has to be `Send` though, as each run moves it to the worker thread that enforces the timeout.
+pub type TotalTimeAlgorithmWithScratch<Input, Output, Scratch> = fn(&Input, &mut Scratch) -> Output;
+/// A prepared enumeration, i.e. an iterator over the solution parts.
+///
+/// Prepared enumerations are `Send`, so they can be moved to a worker thread as long as the input is `Sync`.
+/// All enumeration algorithms in this crate satisfy this bound.
+pub type PreparedEnumerationAlgorithm<'a, Partial> = Box<dyn Iterator<Item = Partial> + Send + 'a>;
 pub type EnumerationAlgorithm<Input, Partial> =
     fn(&Input) -> PreparedEnumerationAlgorithm<'_, Partial>;
 
-pub enum ExperimentAlgorithm<Input, Partial, Output> {
+pub enum ExperimentAlgorithm<Input, Partial, Output, Scratch = ()> {
     TotalTimeAlgorithm(&'static str, TotalTimeAlgorithm<Input, Output>),
+    TotalTimeAlgorithmWithPreprocessing(
+        &'static str,
+        TotalTimeAlgorithmWithPreprocessing<Input, Output>,
+    ),
+    TotalTimeAlgorithmWithScratch(
+        &'static str,
+        TotalTimeAlgorithmWithScratch<Input, Output, Scratch>,
+    ),
     EnumerationAlgorithm(&'static str, EnumerationAlgorithm<Input, Partial>),
 }
 
+impl<Input, Partial, Output, Scratch> ExperimentAlgorithm<Input, Partial, Output, Scratch> {
+    /// Returns the name of the algorithm as used in result file names.
+    pub fn name(&self) -> &'static str {
+        match self {
+            ExperimentAlgorithm::TotalTimeAlgorithm(name, _)
+            | ExperimentAlgorithm::TotalTimeAlgorithmWithPreprocessing(name, _)
+            | ExperimentAlgorithm::TotalTimeAlgorithmWithScratch(name, _)
+            | ExperimentAlgorithm::EnumerationAlgorithm(name, _) => name,
+        }
+    }
+}
+
+/// The output of a total time algorithm or a part enumerated by an enumeration algorithm.
+///
+/// The runner records runs with a failed output or part as failures instead of measuring them,
+/// see [`TotalTimeMeasurement::failed`].
+pub trait Outcome {
+    /// Whether the algorithm could not compute a solution
+    fn is_failure(&self) -> bool {
+        false
+    }
+}
+
+impl<T, E> Outcome for Result<T, E> {
+    fn is_failure(&self) -> bool {
+        self.is_err()
+    }
+}
+
+impl<T> Outcome for Vec<T> {}
+
+macro_rules! impl_infallible_outcome {
+    ($($t:ty)*) => ($(
+        impl Outcome for $t {}
+    )*)
+}
+impl_infallible_outcome!(() bool u8 i8 u16 i16 u32 i32 u64 i64 u128 i128 usize isize f32 f64);
+impl_infallible_outcome!(
+    crate::algorithms::scheduling::flow_shop::SchedulePartial
+    crate::algorithms::scheduling::single_machine::SchedulePartial
+);
+
 pub trait ExperimentGenerator<T: DeserializeOwned + Serialize> {
     /// Returns the canonical path for instances produced by this generator.
     ///
     /// The path is expected to have the form './data/{type}/{subtype}/'.
     fn path() -> String;
 
+    /// Returns the path for the instances of this generator, [`path`](Self::path) unless overridden.
+    fn folder(&self) -> String {
+        Self::path()
+    }
+
     /// Returns the canonical file name for the next generated instance as string.
     ///
     /// The file name is expected to have the form '{size}_{parameter1[-parameter2[...]]}_{RNG state id}' and should not include a file extension.
@@ -34,9 +103,7 @@ pub trait ExperimentGenerator<T: DeserializeOwned + Serialize> {
     fn generate(&mut self) -> T;
 
     fn generate_with_cache(&mut self) -> Result<T, io::IOError> {
-        let mut experiment_path = Self::path();
-        experiment_path.push_str(&self.file_name());
-        experiment_path.push_str(".json");
+        let experiment_path = cache_path(self);
         let file_path = Path::new(&experiment_path);
 
         match io::read_json_from_file(Path::new(&file_path)) {
@@ -55,15 +122,203 @@ pub trait ExperimentGenerator<T: DeserializeOwned + Serialize> {
 
         Ok(instance)
     }
+
+    /// Checks that the cached file for the next instance matches a freshly generated instance.
+    ///
+    /// Unlike [`generate_with_cache`](Self::generate_with_cache), corrupt or partially written cache files
+    /// are reported instead of silently being replaced.
+    /// The generator is advanced just as if the instance had been generated.
+    fn validate_cache(&mut self) -> Result<(), io::IOError> {
+        let experiment_path = cache_path(self);
+        let file_path = Path::new(&experiment_path);
+
+        let cached: serde_json::Value = io::read_json_from_file(file_path)?;
+        let fresh = serde_json::to_value(self.generate()).map_err(|why| {
+            io::IOError::CannotSerialize(experiment_path.clone(), why.to_string())
+        })?;
+
+        if cached == fresh {
+            Ok(())
+        } else {
+            Err(io::IOError::CannotDeserialize(
+                experiment_path,
+                String::from("The cached instance differs from a fresh generation."),
+            ))
+        }
+    }
+}
+
+/// Asserts that the enumeration and the total time algorithm compute solutions of equal quality for the given instance.
+///
+/// The enumerated partials are collected into an `Output` and both solutions are compared by `metric`.
+///
+/// # Panics
+///
+/// Panics if the solutions differ in quality or if the algorithms are not of the expected kind.
+#[cfg(test)]
+pub(crate) fn assert_enum_matches_total<Input, Partial, Output, Scratch, Metric, Quality>(
+    instance: &Input,
+    enumeration_algorithm: &ExperimentAlgorithm<Input, Partial, Output, Scratch>,
+    total_time_algorithm: &ExperimentAlgorithm<Input, Partial, Output, Scratch>,
+    metric: Metric,
+) where
+    Output: FromIterator<Partial>,
+    Metric: Fn(&Output) -> Quality,
+    Quality: PartialEq + std::fmt::Debug,
+    Scratch: Default,
+{
+    let ExperimentAlgorithm::EnumerationAlgorithm(enumeration_name, enumerate) =
+        enumeration_algorithm
+    else {
+        panic!("Expected an enumeration algorithm.");
+    };
+    let enumerated: Output = enumerate(instance).collect();
+
+    let (total_time_name, total) = match total_time_algorithm {
+        ExperimentAlgorithm::TotalTimeAlgorithm(name, algorithm) => (name, algorithm(instance)),
+        ExperimentAlgorithm::TotalTimeAlgorithmWithPreprocessing(name, algorithm) => {
+            (name, algorithm(instance).0)
+        }
+        ExperimentAlgorithm::TotalTimeAlgorithmWithScratch(name, algorithm) => {
+            (name, algorithm(instance, &mut Scratch::default()))
+        }
+        ExperimentAlgorithm::EnumerationAlgorithm(..) => {
+            panic!("Expected a total time algorithm.")
+        }
+    };
+
+    assert_eq!(
+        metric(&enumerated),
+        metric(&total),
+        "{} and {} disagree on the solution quality.",
+        enumeration_name,
+        total_time_name
+    );
+}
+
+/// Returns the path of the folder with the given name inside the system's temporary directory.
+#[cfg(test)]
+pub(crate) fn test_folder(name: &str) -> String {
+    let mut path = std::env::temp_dir().join(name).display().to_string();
+    path.push('/');
+    path
+}
+
+/// A generator for tests that always produces the given instance under the given file name.
+///
+/// Instances and measurements go to the [test folder](test_folder) with the given name.
+/// Tests that run in parallel need distinct folders, as they clean up after themselves.
+#[cfg(test)]
+pub(crate) struct TestGenerator<T> {
+    pub folder: String,
+    pub file_name: String,
+    pub instance: T,
+}
+
+#[cfg(test)]
+impl<T> TestGenerator<T> {
+    pub fn new(folder: &str, file_name: &str, instance: T) -> Self {
+        Self {
+            folder: test_folder(folder),
+            file_name: String::from(file_name),
+            instance,
+        }
+    }
+}
+
+#[cfg(test)]
+impl<T> ExperimentGenerator<T> for TestGenerator<T>
+where
+    T: DeserializeOwned + Serialize + Clone,
+{
+    /// Test generators don't share a folder; see [`folder`](Self::folder).
+    fn path() -> String {
+        unimplemented!("Every test generator has its own folder.")
+    }
+
+    fn folder(&self) -> String {
+        self.folder.clone()
+    }
+
+    fn file_name(&self) -> String {
+        self.file_name.clone()
+    }
+
+    fn generate(&mut self) -> T {
+        self.instance.clone()
+    }
+}
+
+/// Makes the given parameter label safe to use in a file name as returned by [`ExperimentGenerator::file_name`].
+///
+/// Underscores separate the parts of the file name and slashes separate folders, so both are replaced by hyphens.
+pub fn sanitize_parameter(label: &str) -> String {
+    label.replace(['_', '/', '\\'], "-")
+}
+
+/// Checks that the given file name has the form '{size}_{parameters}_{RNG state id}' expected by the aggregation.
+pub fn validate_file_name(file_name: &str) -> Result<(), io::IOError> {
+    let parts: Vec<&str> = file_name.split('_').collect();
+    let valid = match parts[..] {
+        [size, parameters, rng_id] => {
+            size.parse::<u32>().is_ok() && !parameters.is_empty() && !rng_id.is_empty()
+        }
+        _ => false,
+    };
+
+    if valid && !file_name.contains(['/', '\\']) {
+        Ok(())
+    } else {
+        Err(io::IOError::CannotWrite(
+            String::from(file_name),
+            String::from(
+                "Expected a file name of the form '{size}_{parameters}_{RNG state id}' \
+                (use sanitize_parameter for parameter labels).",
+            ),
+        ))
+    }
+}
+
+/// Returns the path of the cache file for the next instance of the given generator.
+fn cache_path<T, Generator>(generator: &Generator) -> String
+where
+    T: DeserializeOwned + Serialize,
+    Generator: ExperimentGenerator<T> + ?Sized,
+{
+    let mut experiment_path = generator.folder();
+    experiment_path.push_str(&generator.file_name());
+    experiment_path.push_str(".json");
+    experiment_path
 }
 
 #[derive(Serialize, Deserialize)]
 pub struct TotalTimeMeasurement {
     /// The total time in ns
     pub total_time: u64,
+    /// The preprocessing time in ns as reported by the algorithm (0 if it doesn't report any)
+    #[serde(default)]
+    pub preprocessing: u64,
+    /// The peak number of heap bytes allocated during the run (only with the `mem-profiling` feature)
+    #[serde(default)]
+    pub peak_bytes: Option<u64>,
+    /// Marks a run in which the algorithm could not compute a solution; all times are 0 then
+    #[serde(default)]
+    pub failed: bool,
 }
 
-#[derive(Serialize, Deserialize)]
+impl TotalTimeMeasurement {
+    /// The marker for a run in which the algorithm could not compute a solution
+    pub fn failure() -> Self {
+        Self {
+            total_time: 0,
+            preprocessing: 0,
+            peak_bytes: None,
+            failed: true,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
 pub struct EnumerationMeasurement {
     /// The total time in ns
     pub total_time: u64,
@@ -79,9 +334,114 @@ pub struct EnumerationMeasurement {
     pub delay_max: u64,
     /// The average delay in ns
     pub delay_avg: f64,
+    /// The sample variance of the delays in ns²
+    #[serde(default)]
+    pub delay_variance: f64,
+    /// The peak number of heap bytes allocated during the run (only with the `mem-profiling` feature)
+    #[serde(default)]
+    pub peak_bytes: Option<u64>,
+    /// Marks a run in which some enumerated part was a failure; all times are 0 then
+    #[serde(default)]
+    pub failed: bool,
     /*
        We could also keep track of:
-       - variance
        - all (?) or some random subset of delays
     */
 }
+
+impl EnumerationMeasurement {
+    /// The marker for a run in which the algorithm could not compute a solution
+    pub fn failure() -> Self {
+        Self {
+            total_time: 0,
+            preprocessing: 0,
+            first_output: 0,
+            delays: 0,
+            delay_min: 0,
+            delay_max: 0,
+            delay_avg: 0.0,
+            delay_variance: 0.0,
+            peak_bytes: None,
+            failed: true,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::random_generators::numbers::{Rng, TaillardLCG};
+
+    use super::*;
+
+    fn numbers_generator(seed: usize) -> TestGenerator<Vec<i32>> {
+        let mut rng = TaillardLCG::from_seed(seed);
+        let file_name = format!("100_numbers_{}", rng.state_id());
+        TestGenerator::new(
+            "exp_lib_test_cache",
+            &file_name,
+            (0..100).map(|_| rng.next_i32(0..=1000)).collect(),
+        )
+    }
+
+    #[test]
+    fn test_validate_cache() {
+        let seed = 1234;
+        let mut generator = numbers_generator(seed);
+        let file_path = std::path::PathBuf::from(cache_path(&generator));
+        let _ = std::fs::remove_file(&file_path);
+        generator.generate_with_cache().unwrap();
+
+        let mut generator = numbers_generator(seed);
+        let valid = generator.validate_cache();
+
+        // truncate the cache as if writing it had been interrupted
+        let content = std::fs::read_to_string(&file_path).unwrap();
+        std::fs::write(&file_path, &content[..content.len() / 2]).unwrap();
+
+        let mut generator = numbers_generator(seed);
+        let truncated = generator.validate_cache();
+        std::fs::remove_file(&file_path).unwrap();
+
+        assert!(valid.is_ok());
+        assert!(matches!(
+            truncated,
+            Err(io::IOError::CannotDeserialize(_, _))
+        ));
+    }
+
+    #[test]
+    fn test_validate_cache_mismatch() {
+        let seed = 4321;
+        let mut generator = numbers_generator(seed);
+        let path = cache_path(&generator);
+        let file_path = Path::new(&path);
+        io::write_json_to_file(file_path, vec![1, 2, 3]).unwrap();
+
+        let result = generator.validate_cache();
+        std::fs::remove_file(file_path).unwrap();
+
+        assert!(matches!(result, Err(io::IOError::CannotDeserialize(_, _))));
+    }
+
+    #[test]
+    fn test_sanitize_parameter() {
+        assert_eq!(sanitize_parameter("log_2(n)/n"), "log-2(n)-n");
+        assert_eq!(sanitize_parameter("0.25"), "0.25");
+    }
+
+    #[test]
+    fn test_validate_file_name() {
+        assert!(validate_file_name("100_0.25_TLCG-42").is_ok());
+        assert!(validate_file_name("100_0.25-7_TLCG-42").is_ok());
+        assert!(
+            validate_file_name(&format!("100_{}_TLCG-42", sanitize_parameter("log_2(n)/n")))
+                .is_ok()
+        );
+
+        assert!(validate_file_name("100_log_2(n)_TLCG-42").is_err());
+        assert!(validate_file_name("100_sqrt(n)/n_TLCG-42").is_err());
+        assert!(validate_file_name("many_0.25_TLCG-42").is_err());
+        assert!(validate_file_name("100__TLCG-42").is_err());
+        assert!(validate_file_name("100_0.25").is_err());
+    }
+}
@@ -1,83 +1,303 @@
-use std::{path::Path, slice, time::Instant};
+use std::{
+    path::Path,
+    sync::{mpsc, Arc},
+    thread,
+    time::{Duration, Instant},
+};
 
 use serde::{de::DeserializeOwned, Serialize};
 
+#[cfg(feature = "mem-profiling")]
+use crate::helpers::counting_alloc;
 use crate::io;
 
 use rand::seq::SliceRandom;
+use rayon::prelude::*;
 
 use super::{
-    aggregator::Aggregation, sets::ExperimentOptions, EnumerationAlgorithm, EnumerationMeasurement,
-    ExperimentAlgorithm, ExperimentGenerator, TotalTimeAlgorithm, TotalTimeMeasurement,
+    aggregator::Aggregation, sets::ExperimentOptions, validate_file_name, EnumerationAlgorithm,
+    EnumerationMeasurement, ExperimentAlgorithm, ExperimentGenerator, Outcome, TotalTimeAlgorithm,
+    TotalTimeAlgorithmWithPreprocessing, TotalTimeAlgorithmWithScratch, TotalTimeMeasurement,
 };
 
-pub fn run_experiment<Generator, Input, Partial, Output>(
+/// Number of measurements after which the result files are flushed.
+const MEASUREMENT_FLUSH_EVERY: usize = 1;
+
+/// Runs all algorithms `number_of_runs` times on the instance of the generator and appends the measurements to one result file per algorithm.
+///
+/// Before the measured runs, `options.warmup_runs` runs are made whose measurements are discarded.
+///
+/// If `options.per_algorithm_timeout` is set, each run happens on a separate thread.
+/// A run that doesn't finish in time is abandoned and not recorded, and the algorithm is skipped
+/// for the remaining runs on this instance. Abandoned threads can't be stopped and keep running
+/// in the background until the algorithm finishes. Running on other threads is why the input and
+/// the scratch have to be `Send` and `'static`.
+pub fn run_experiment<Generator, Input, Partial, Output, Scratch>(
     generator: &mut Generator,
     options: ExperimentOptions,
     number_of_runs: u32,
-    algorithms: &[ExperimentAlgorithm<Input, Partial, Output>],
+    algorithms: &[ExperimentAlgorithm<Input, Partial, Output, Scratch>],
 ) -> Result<(), io::IOError>
 where
-    Input: DeserializeOwned + Serialize,
+    Input: DeserializeOwned + Serialize + Send + Sync + 'static,
+    Partial: Outcome + 'static,
+    Output: Outcome + 'static,
     Generator: ExperimentGenerator<Input>,
+    Scratch: Default + Send + 'static,
 {
-    let mut instance_path = Generator::path();
-    instance_path.push_str(&generator.file_name());
-    let instance = if options.cache_instances {
+    let file_name = generator.file_name();
+    validate_file_name(&file_name)?;
+    let mut instance_path = generator.folder();
+    instance_path.push_str(&file_name);
+    let instance = Arc::new(if options.cache_instances {
         generator.generate_with_cache()?
     } else {
         generator.generate()
-    };
+    });
+    let timeout = options.per_algorithm_timeout;
 
     log::info!("Run experiments for {}.", instance_path);
 
-    let mut algorithms: Vec<_> = algorithms.iter().collect();
+    // one scratch and one result file per algorithm, reused across all runs on this instance;
+    // algorithms that timed out are skipped in later runs
+    let mut prepared = Vec::with_capacity(algorithms.len());
+    for algorithm in algorithms {
+        let result_file_name = format!(
+            "{}.{}.{}",
+            instance_path,
+            algorithm.name(),
+            options.measurement_format.extension()
+        );
+        match io::MeasurementWriter::new(Path::new(&result_file_name), MEASUREMENT_FLUSH_EVERY) {
+            Ok(writer) => prepared.push((algorithm, Scratch::default(), writer, false)),
+            Err(why) => {
+                // nothing was measured yet, so the temporary files of the other writers are not needed
+                for (_, _, writer, _) in prepared {
+                    if let Err(why) = writer.discard() {
+                        log::error!("{}", why);
+                    }
+                }
+                return Err(why);
+            }
+        }
+    }
+    let mut algorithms = prepared;
     let mut rng = rand::thread_rng();
 
-    for run in 1..=number_of_runs {
+    // warmup runs are shuffled and run just like measured runs, but their measurements are discarded
+    let warmup_runs = (1..=options.warmup_runs).map(|run| (run, false));
+    let measured_runs = (1..=number_of_runs).map(|run| (run, true));
+
+    for (run, measured) in warmup_runs.chain(measured_runs) {
         algorithms.shuffle(&mut rng);
 
-        for algorithm in algorithms.iter() {
-            match algorithm {
-                ExperimentAlgorithm::TotalTimeAlgorithm(name, total_time_algorithm) => {
-                    let measurement = run_total_time_algorithm(&instance, total_time_algorithm);
-                    let result_file_name = format!("{}.{}.csv", instance_path, name);
-                    io::append_csv_to_file(
-                        Path::new(&result_file_name),
-                        slice::from_ref(&measurement),
-                    )?;
+        for (algorithm, scratch, writer, timed_out) in algorithms.iter_mut() {
+            if *timed_out {
+                continue;
+            }
+            let instance = Arc::clone(&instance);
+            let finished = match **algorithm {
+                ExperimentAlgorithm::TotalTimeAlgorithm(_, total_time_algorithm) => {
+                    let measurement = run_with_timeout(timeout, move || {
+                        run_total_time_algorithm(&*instance, &total_time_algorithm)
+                    });
+                    record(writer, measurement, measured)?
+                }
+                ExperimentAlgorithm::TotalTimeAlgorithmWithPreprocessing(_, algorithm) => {
+                    let measurement = run_with_timeout(timeout, move || {
+                        run_total_time_algorithm_with_preprocessing(&*instance, &algorithm)
+                    });
+                    record(writer, measurement, measured)?
+                }
+                ExperimentAlgorithm::TotalTimeAlgorithmWithScratch(_, algorithm) => {
+                    let mut moved_scratch = std::mem::take(scratch);
+                    let result = run_with_timeout(timeout, move || {
+                        let measurement = run_total_time_algorithm_with_scratch(
+                            &*instance,
+                            &algorithm,
+                            &mut moved_scratch,
+                        );
+                        (measurement, moved_scratch)
+                    });
+                    let measurement = result.map(|(m, moved_scratch)| {
+                        *scratch = moved_scratch;
+                        m
+                    });
+                    record(writer, measurement, measured)?
                 }
-                ExperimentAlgorithm::EnumerationAlgorithm(name, enumeration_algorithm) => {
-                    let measurement = run_enumeration_algorithm(&instance, enumeration_algorithm);
-                    let result_file_name = format!("{}.{}.csv", instance_path, name);
-                    io::append_csv_to_file(
-                        Path::new(&result_file_name),
-                        slice::from_ref(&measurement),
-                    )?;
+                ExperimentAlgorithm::EnumerationAlgorithm(_, enumeration_algorithm) => {
+                    let measurement = run_with_timeout(timeout, move || {
+                        run_enumeration_algorithm(&*instance, &enumeration_algorithm)
+                    });
+                    record(writer, measurement, measured)?
                 }
+            };
+
+            match finished {
+                None => {
+                    log::warn!(
+                        "{} timed out on {}; skipping it for the remaining runs.",
+                        algorithm.name(),
+                        instance_path
+                    );
+                    *timed_out = true;
+                }
+                Some(true) => log::warn!(
+                    "{} could not compute a solution for {}.",
+                    algorithm.name(),
+                    instance_path
+                ),
+                Some(false) => {}
             }
         }
 
-        log::info!("{:2}/{:2}", run, number_of_runs);
+        if measured {
+            log::info!("{:2}/{:2}", run, number_of_runs);
+        } else {
+            log::info!("warmup {:2}/{:2}", run, options.warmup_runs);
+        }
+    }
+
+    for (_, _, writer, _) in algorithms {
+        writer.finish()?;
     }
 
     log::info!("Finished experiments for {}.", instance_path);
     Ok(())
 }
 
+/// Runs the experiment for the instances generated from each of the given seeds concurrently.
+///
+/// Each instance is generated and run in isolation, i.e. with its own generator, its own shuffle RNG
+/// and its own result files (which are distinct since the file name includes the RNG state).
+/// Note that concurrent runs compete for resources, which may distort the measured times.
+pub fn run_experiment_parallel<Generator, Input, Partial, Output, Scratch>(
+    seeds: impl IntoParallelIterator<Item = usize>,
+    new_generator: impl Fn(usize) -> Generator + Sync,
+    options: ExperimentOptions,
+    number_of_runs: u32,
+    algorithms: &[ExperimentAlgorithm<Input, Partial, Output, Scratch>],
+) -> Result<(), io::IOError>
+where
+    Input: DeserializeOwned + Serialize + Send + Sync + 'static,
+    Partial: Outcome + 'static,
+    Output: Outcome + 'static,
+    Generator: ExperimentGenerator<Input>,
+    Scratch: Default + Send + 'static,
+{
+    seeds.into_par_iter().try_for_each(|seed| {
+        let mut generator = new_generator(seed);
+        run_experiment(&mut generator, options, number_of_runs, algorithms)
+    })
+}
+
+/// Measurements that mark runs in which the algorithm could not compute a solution
+trait Measurement: Serialize {
+    fn failed(&self) -> bool;
+}
+
+impl Measurement for TotalTimeMeasurement {
+    fn failed(&self) -> bool {
+        self.failed
+    }
+}
+
+impl Measurement for EnumerationMeasurement {
+    fn failed(&self) -> bool {
+        self.failed
+    }
+}
+
+/// Writes the measurement of a finished measured run.
+///
+/// Returns whether the run failed, or `None` if the run didn't finish.
+fn record<T: Measurement>(
+    writer: &mut io::MeasurementWriter,
+    measurement: Option<T>,
+    measured: bool,
+) -> Result<Option<bool>, io::IOError> {
+    match measurement {
+        Some(m) if measured => writer.write(&m).map(|_| Some(m.failed())),
+        Some(m) => Ok(Some(m.failed())),
+        None => Ok(None),
+    }
+}
+
+/// Runs `work` on a separate thread and returns its result, or `None` if it didn't finish within `timeout`.
+///
+/// Without a timeout, `work` simply runs on the current thread.
+fn run_with_timeout<T: Send + 'static>(
+    timeout: Option<Duration>,
+    work: impl FnOnce() -> T + Send + 'static,
+) -> Option<T> {
+    let Some(timeout) = timeout else {
+        return Some(work());
+    };
+
+    let (sender, receiver) = mpsc::channel();
+    thread::spawn(move || {
+        // the receiver is gone if we timed out
+        let _ = sender.send(work());
+    });
+    receiver.recv_timeout(timeout).ok()
+}
+
 fn run_total_time_algorithm<Input, Output>(
     input: &Input,
     algorithm: &TotalTimeAlgorithm<Input, Output>,
 ) -> TotalTimeMeasurement
 where
     Input: DeserializeOwned + Serialize,
+    Output: Outcome,
+{
+    measure_total_time(|| (algorithm(input), 0))
+}
+
+fn run_total_time_algorithm_with_preprocessing<Input, Output>(
+    input: &Input,
+    algorithm: &TotalTimeAlgorithmWithPreprocessing<Input, Output>,
+) -> TotalTimeMeasurement
+where
+    Input: DeserializeOwned + Serialize,
+    Output: Outcome,
+{
+    measure_total_time(|| algorithm(input))
+}
+
+fn run_total_time_algorithm_with_scratch<Input, Output, Scratch>(
+    input: &Input,
+    algorithm: &TotalTimeAlgorithmWithScratch<Input, Output, Scratch>,
+    scratch: &mut Scratch,
+) -> TotalTimeMeasurement
+where
+    Input: DeserializeOwned + Serialize,
+    Output: Outcome,
 {
+    measure_total_time(|| (algorithm(input, scratch), 0))
+}
+
+/// Measures the run of `algorithm`, or returns a [failure marker](TotalTimeMeasurement::failure)
+/// if its output is a failure.
+fn measure_total_time<Output: Outcome>(
+    algorithm: impl FnOnce() -> (Output, u64),
+) -> TotalTimeMeasurement {
+    let memory_baseline = start_peak_measurement();
     let start = Instant::now();
-    algorithm(input);
+    let (output, preprocessing) = algorithm();
+    let failed = output.is_failure();
+    drop(output);
     // overflow for ~584 years -> not relevant for us
     let total_time = start.elapsed().as_nanos() as u64;
 
-    TotalTimeMeasurement { total_time }
+    if failed {
+        return TotalTimeMeasurement::failure();
+    }
+    TotalTimeMeasurement {
+        total_time,
+        preprocessing,
+        peak_bytes: peak_bytes_since(memory_baseline),
+        failed: false,
+    }
 }
 
 fn run_enumeration_algorithm<Input, Partial>(
@@ -86,20 +306,37 @@ fn run_enumeration_algorithm<Input, Partial>(
 ) -> EnumerationMeasurement
 where
     Input: DeserializeOwned + Serialize,
+    Partial: Outcome,
+{
+    measure_enumeration(|| algorithm(input))
+}
+
+/// Measures preprocessing and delays of the enumeration that `prepare` sets up.
+///
+/// Returns a [failure marker](EnumerationMeasurement::failure) if any enumerated part is a failure.
+pub(crate) fn measure_enumeration<Partial, Enumeration>(
+    prepare: impl FnOnce() -> Enumeration,
+) -> EnumerationMeasurement
+where
+    Partial: Outcome,
+    Enumeration: Iterator<Item = Partial>,
 {
     let mut first_output = 0;
+    let mut failed = false;
     let mut delay_aggregation = Aggregation::new();
 
+    let memory_baseline = start_peak_measurement();
     let start = Instant::now();
-    let enumeration_iterator = algorithm(input);
+    let enumeration_iterator = prepare();
 
     // overflow for ~584 years -> not relevant for us
     let preprocessing = start.elapsed().as_nanos() as u64;
 
     let mut delay_start = Instant::now();
-    for _ in enumeration_iterator {
+    for partial in enumeration_iterator {
         // overflow for ~584 years -> not relevant for us
         let delay = delay_start.elapsed().as_nanos() as u64;
+        failed |= partial.is_failure();
 
         delay_aggregation.push(delay);
 
@@ -114,6 +351,9 @@ where
     // overflow for ~584 years -> not relevant for us
     let total_time = start.elapsed().as_nanos() as u64;
 
+    if failed {
+        return EnumerationMeasurement::failure();
+    }
     EnumerationMeasurement {
         total_time,
         preprocessing,
@@ -122,5 +362,313 @@ where
         delay_min: delay_aggregation.min,
         delay_max: delay_aggregation.max,
         delay_avg: delay_aggregation.avg,
+        delay_variance: delay_aggregation.variance(),
+        peak_bytes: peak_bytes_since(memory_baseline),
+        failed: false,
+    }
+}
+
+/// Resets the peak memory counter and returns the number of currently allocated bytes
+/// if the `mem-profiling` feature is enabled; 0 otherwise.
+fn start_peak_measurement() -> u64 {
+    #[cfg(feature = "mem-profiling")]
+    {
+        counting_alloc::reset_peak();
+        counting_alloc::current_bytes()
+    }
+    #[cfg(not(feature = "mem-profiling"))]
+    0
+}
+
+/// The peak number of allocated bytes above `baseline` since [`start_peak_measurement`]
+/// if the `mem-profiling` feature is enabled; `None` otherwise.
+fn peak_bytes_since(baseline: u64) -> Option<u64> {
+    #[cfg(feature = "mem-profiling")]
+    {
+        Some(counting_alloc::peak_bytes().saturating_sub(baseline))
+    }
+    #[cfg(not(feature = "mem-profiling"))]
+    {
+        let _ = baseline;
+        None
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::HashMap;
+
+    use crate::experiments::{
+        aggregator, test_folder, PreparedEnumerationAlgorithm, TestGenerator,
+    };
+    use crate::random_generators::numbers::{Rng, TaillardLCG};
+
+    use super::*;
+
+    fn sum_with_preprocessing(input: &u64) -> (u64, u64) {
+        let start = Instant::now();
+        let mut values: Vec<u64> = (0..*input).rev().collect();
+        values.sort_unstable();
+        let preprocessing = start.elapsed().as_nanos() as u64;
+
+        (values.iter().sum(), preprocessing)
+    }
+
+    #[test]
+    fn test_total_time_with_preprocessing() {
+        let measurement = run_total_time_algorithm_with_preprocessing(
+            &10_000u64,
+            &(sum_with_preprocessing as TotalTimeAlgorithmWithPreprocessing<_, _>),
+        );
+
+        assert!(measurement.preprocessing > 0);
+        assert!(measurement.preprocessing <= measurement.total_time);
+    }
+
+    #[test]
+    fn test_peak_bytes() {
+        let n = 1 << 20;
+        let measurement = run_total_time_algorithm(
+            &n,
+            &((|n: &usize| Vec::<u64>::with_capacity(*n)) as TotalTimeAlgorithm<_, _>),
+        );
+
+        if cfg!(feature = "mem-profiling") {
+            assert!(measurement.peak_bytes.unwrap() >= (n * std::mem::size_of::<u64>()) as u64);
+        } else {
+            assert_eq!(measurement.peak_bytes, None);
+        }
+    }
+
+    #[test]
+    fn test_total_time_without_preprocessing() {
+        let measurement = run_total_time_algorithm(
+            &10_000u64,
+            &((|input: &u64| (0..*input).sum::<u64>()) as TotalTimeAlgorithm<_, _>),
+        );
+
+        assert_eq!(measurement.preprocessing, 0);
+    }
+
+    #[test]
+    fn test_enumeration_delay_variance() {
+        // two delays: a long one followed by a short one
+        let measurement = measure_enumeration(|| {
+            [2, 0].into_iter().map(|ms| {
+                std::thread::sleep(std::time::Duration::from_millis(ms));
+            })
+        });
+
+        assert_eq!(measurement.delays, 2);
+        assert!(measurement.delay_min < measurement.delay_max);
+        // the sample variance of two values is half their squared difference
+        let expected = (measurement.delay_max - measurement.delay_min).pow(2) as f64 / 2.0;
+        assert!((measurement.delay_variance - expected).abs() <= expected * 1e-9);
+    }
+
+    /// Returns the median of the input, using the scratch to sort a copy.
+    fn median_with_scratch(input: &u64, scratch: &mut Vec<u64>) -> u64 {
+        scratch.clear();
+        scratch.extend((0..*input).rev());
+        scratch.sort_unstable();
+        scratch[scratch.len() / 2]
+    }
+
+    #[test]
+    fn test_total_time_with_scratch() {
+        let algorithm: TotalTimeAlgorithmWithScratch<_, _, _> = median_with_scratch;
+        let mut scratch = Vec::new();
+
+        run_total_time_algorithm_with_scratch(&1_000u64, &algorithm, &mut scratch);
+        let buffer = scratch.as_ptr();
+        for _ in 0..3 {
+            run_total_time_algorithm_with_scratch(&1_000u64, &algorithm, &mut scratch);
+            assert_eq!(scratch.as_ptr(), buffer, "Scratch buffer was reallocated.");
+            assert_eq!(algorithm(&1_000, &mut scratch), 500);
+        }
+    }
+
+    #[test]
+    fn test_run_experiment_parallel() {
+        let folder = test_folder("exp_lib_test_parallel");
+        let _ = std::fs::remove_dir_all(&folder);
+
+        let algorithms = [ExperimentAlgorithm::<_, (), _>::TotalTimeAlgorithm(
+            "sum",
+            (|input: &Vec<u64>| input.iter().sum::<u64>()) as TotalTimeAlgorithm<_, _>,
+        )];
+        run_experiment_parallel(
+            1..=4,
+            |seed| {
+                let mut rng = TaillardLCG::from_seed(seed);
+                let file_name = format!("100_numbers_{}", rng.state_id());
+                let numbers = (0..100).map(|_| rng.next_i32(0..=1000) as u64).collect();
+                TestGenerator::new("exp_lib_test_parallel", &file_name, numbers)
+            },
+            ExperimentOptions::default(),
+            3,
+            &algorithms,
+        )
+        .unwrap();
+
+        let mut files: Vec<_> = std::fs::read_dir(&folder)
+            .unwrap()
+            .map(|entry| entry.unwrap().path())
+            .collect();
+        files.sort();
+        let measurements: Vec<Vec<TotalTimeMeasurement>> = files
+            .iter()
+            .map(|file| io::read_csv_from_file(file).unwrap())
+            .collect();
+        std::fs::remove_dir_all(&folder).unwrap();
+
+        assert_eq!(files.len(), 4);
+        for (seed, file) in (1..=4).zip(&files) {
+            assert_eq!(
+                file.file_name().unwrap().to_str().unwrap(),
+                format!("100_numbers_TLCG-{}.sum.csv", seed)
+            );
+        }
+        assert!(measurements.iter().all(|m| m.len() == 3));
+    }
+
+    #[test]
+    fn test_run_experiment_rejects_underscore_label() {
+        let mut generator = TestGenerator::new(
+            "exp_lib_test_underscore_label",
+            "100_log_2(n)_TLCG-1",
+            vec![1u64, 2, 3],
+        );
+        let algorithms = [ExperimentAlgorithm::<_, (), _>::TotalTimeAlgorithm(
+            "sum",
+            (|input: &Vec<u64>| input.iter().sum::<u64>()) as TotalTimeAlgorithm<_, _>,
+        )];
+        let result = run_experiment(&mut generator, ExperimentOptions::default(), 1, &algorithms);
+
+        assert!(matches!(result, Err(io::IOError::CannotWrite(_, _))));
+        assert!(!Path::new(&generator.folder).exists());
+    }
+
+    #[test]
+    fn test_run_with_timeout() {
+        assert_eq!(run_with_timeout(None, || 42), Some(42));
+        assert_eq!(
+            run_with_timeout(Some(Duration::from_secs(10)), || 42),
+            Some(42)
+        );
+        assert_eq!(
+            run_with_timeout(Some(Duration::from_millis(10)), || {
+                thread::sleep(Duration::from_secs(1));
+                42
+            }),
+            None
+        );
+    }
+
+    #[test]
+    fn test_run_experiment_timeout() {
+        let mut generator = TestGenerator::new("exp_lib_test_timeout", "1_sleep_TLCG-1", 1_000u64);
+        let folder = generator.folder.clone();
+        let _ = std::fs::remove_dir_all(&folder);
+
+        let options = ExperimentOptions {
+            per_algorithm_timeout: Some(Duration::from_millis(50)),
+            ..Default::default()
+        };
+        let algorithms = [
+            ExperimentAlgorithm::<_, (), _>::TotalTimeAlgorithm(
+                "fast",
+                (|_: &u64| ()) as TotalTimeAlgorithm<_, _>,
+            ),
+            ExperimentAlgorithm::<_, (), _>::TotalTimeAlgorithm(
+                "slow",
+                (|ms: &u64| thread::sleep(Duration::from_millis(*ms))) as TotalTimeAlgorithm<_, _>,
+            ),
+        ];
+        run_experiment(&mut generator, options, 3, &algorithms).unwrap();
+
+        let read = |algorithm: &str| -> Vec<TotalTimeMeasurement> {
+            let file = format!("{}1_sleep_TLCG-1.{}.csv", folder, algorithm);
+            io::read_csv_from_file(Path::new(&file)).unwrap()
+        };
+        let (fast, slow) = (read("fast"), read("slow"));
+        std::fs::remove_dir_all(&folder).unwrap();
+
+        assert_eq!(fast.len(), 3);
+        assert!(slow.is_empty());
+    }
+
+    #[test]
+    fn test_run_experiment_warmup() {
+        let mut generator = TestGenerator::new("exp_lib_test_warmup", "1_warmup_TLCG-1", 1_000u64);
+        let folder = generator.folder.clone();
+        let _ = std::fs::remove_dir_all(&folder);
+
+        let options = ExperimentOptions {
+            warmup_runs: 2,
+            ..Default::default()
+        };
+        let algorithms = [ExperimentAlgorithm::<_, (), _>::TotalTimeAlgorithm(
+            "sum",
+            (|input: &u64| (0..*input).sum::<u64>()) as TotalTimeAlgorithm<_, _>,
+        )];
+        run_experiment(&mut generator, options, 3, &algorithms).unwrap();
+
+        let file = format!("{}1_warmup_TLCG-1.sum.csv", folder);
+        let measurements: Vec<TotalTimeMeasurement> =
+            io::read_csv_from_file(Path::new(&file)).unwrap();
+        std::fs::remove_dir_all(&folder).unwrap();
+
+        assert_eq!(measurements.len(), 3);
+    }
+
+    #[test]
+    fn test_run_experiment_records_failures() {
+        let mut generator = TestGenerator::new("exp_lib_test_failure", "20_numbers_TLCG-1", 20u64);
+        let folder = generator.folder.clone();
+        let _ = std::fs::remove_dir_all(&folder);
+
+        let algorithms: [ExperimentAlgorithm<_, _, _>; 2] = [
+            ExperimentAlgorithm::TotalTimeAlgorithm(
+                "small",
+                (|input: &u64| if *input > 10 { Err(()) } else { Ok(()) })
+                    as TotalTimeAlgorithm<_, _>,
+            ),
+            ExperimentAlgorithm::EnumerationAlgorithm(
+                "small_enum",
+                (|input: &u64| {
+                    Box::new((0..*input).map(|i| if i > 10 { Err(()) } else { Ok(i) }))
+                        as PreparedEnumerationAlgorithm<_>
+                }) as EnumerationAlgorithm<_, _>,
+            ),
+        ];
+        run_experiment(&mut generator, ExperimentOptions::default(), 3, &algorithms).unwrap();
+        for algorithm in &algorithms {
+            aggregator::aggregate_folder(Path::new(&folder), algorithm, &io::CsvOptions::default())
+                .unwrap();
+        }
+
+        let file = format!("{}20_numbers_TLCG-1.small.csv", folder);
+        let total_time: Vec<TotalTimeMeasurement> =
+            io::read_csv_from_file(Path::new(&file)).unwrap();
+        let file = format!("{}20_numbers_TLCG-1.small_enum.csv", folder);
+        let enumeration: Vec<EnumerationMeasurement> =
+            io::read_csv_from_file(Path::new(&file)).unwrap();
+        let read_aggregated = |algorithm: &str| -> Vec<HashMap<String, f64>> {
+            let file = format!("{}aggregated_numbers.{}.csv", folder, algorithm);
+            io::read_csv_from_file(Path::new(&file)).unwrap()
+        };
+        let aggregated = [read_aggregated("small"), read_aggregated("small_enum")];
+        std::fs::remove_dir_all(&folder).unwrap();
+
+        assert_eq!(total_time.len(), 3);
+        assert!(total_time.iter().all(|m| m.failed));
+        assert_eq!(enumeration.len(), 3);
+        assert!(enumeration.iter().all(|m| m.failed));
+        for rows in aggregated {
+            assert_eq!(rows.len(), 1);
+            assert_eq!(rows[0]["failures"], 3.0);
+            assert_eq!(rows[0]["total_time"], 0.0);
+        }
     }
 }
@@ -76,7 +76,7 @@ fn run(options: ExperimentOptions) {
 
     for jobs in job_numbers
         .into_iter()
-        .filter(|&size| !options.max_size.is_some_and(|max| size > max))
+        .filter(|&size| options.max_size.is_none_or(|max| size <= max))
     {
         log::info!("Run F2||C_max solver for {} jobs.", jobs);
         for i in 1..=instances_per_size {
@@ -99,6 +99,6 @@ fn run(options: ExperimentOptions) {
     }
 }
 
-fn aggregate() {
-    super::aggregate::<flow_shop::Taillard, _, _, _>(&ALGORITHMS)
+fn aggregate(options: ExperimentOptions) {
+    super::aggregate::<flow_shop::Taillard, _, _, _, _>(&ALGORITHMS, options)
 }
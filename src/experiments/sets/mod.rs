@@ -2,28 +2,71 @@ pub mod f2_cmax;
 pub mod prec_cmax;
 pub mod rj_cmax;
 
+mod parameter_sweep;
+
+pub use parameter_sweep::ParameterSweep;
+
+use std::{path::Path, time::Duration};
+
 use serde::{de::DeserializeOwned, Serialize};
 
+use crate::io::{CsvOptions, MeasurementFormat};
+
 use super::{aggregator, ExperimentAlgorithm, ExperimentGenerator};
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, Default)]
 pub struct ExperimentOptions {
     pub max_size: Option<u32>,
     pub cache_instances: bool,
+    /// Abandon runs of an algorithm that take longer than this; see [`super::runner::run_experiment`].
+    pub per_algorithm_timeout: Option<Duration>,
+    /// Number of unmeasured runs of each algorithm before the measured runs on every instance
+    pub warmup_runs: u32,
+    /// File format of the measurement files
+    pub measurement_format: MeasurementFormat,
+    /// Significant digits of the decimal numbers in aggregated files, see [`CsvOptions::float_precision`]
+    pub float_precision: Option<usize>,
 }
 
 pub struct ExperimentSet {
     pub run: fn(options: ExperimentOptions),
-    pub aggregate: fn(),
+    pub aggregate: fn(options: ExperimentOptions),
 }
 
-fn aggregate<Generator, Input, Partial, Output>(
-    algorithms: &[ExperimentAlgorithm<Input, Partial, Output>],
+fn aggregate<Generator, Input, Partial, Output, Scratch>(
+    algorithms: &[ExperimentAlgorithm<Input, Partial, Output, Scratch>],
+    options: ExperimentOptions,
 ) where
     Input: Serialize + DeserializeOwned,
     Generator: ExperimentGenerator<Input>,
 {
+    let csv_options = CsvOptions {
+        float_precision: options.float_precision,
+        ..Default::default()
+    };
     for algorithm in algorithms {
-        aggregator::aggregate::<Generator, _, _, _>(algorithm).unwrap();
+        if let Err(why) =
+            aggregator::aggregate_with_options::<Generator, _, _, _, _>(algorithm, &csv_options)
+        {
+            log::error!("Could not aggregate {}: {}", algorithm.name(), why);
+        }
+    }
+
+    let folder = Generator::path();
+    let summary = match aggregator::summarize(Path::new(&folder), algorithms, "total_time") {
+        Ok(summary) => summary,
+        Err(why) => {
+            log::error!("Could not summarize {}: {}", folder, why);
+            return;
+        }
+    };
+    for row in summary.rows {
+        log::info!(
+            "Fastest for {} at size {}: {} ({} ns)",
+            row.parameters,
+            row.size,
+            row.algorithm,
+            row.value
+        );
     }
 }
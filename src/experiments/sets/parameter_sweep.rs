@@ -0,0 +1,102 @@
+/// Values of a parameter to run an experiment set for, each with a label for logging.
+///
+/// ```
+/// use exp_lib::experiments::sets::ParameterSweep;
+///
+/// let n = 100.0_f64;
+/// let sweep = ParameterSweep::log(0.25, 0.03125, 4).with_label(n.sqrt() / n, "sqrt(n)/n");
+/// let values: Vec<f64> = sweep.values().collect();
+/// assert_eq!(values, [0.25, 0.125, 0.0625, 0.03125, 0.1]);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct ParameterSweep {
+    parameters: Vec<(f64, String)>,
+}
+
+impl ParameterSweep {
+    /// `steps` equally spaced values from `start` to `end` (both inclusive).
+    pub fn linear(start: f64, end: f64, steps: usize) -> Self {
+        Self::from_values(spaced(start, end, steps))
+    }
+
+    /// `steps` values from `start` to `end` (both inclusive) that are equally spaced on a logarithmic scale.
+    ///
+    /// The values are spaced in base 2, so sweeps over powers of two yield exact values.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `start` or `end` is not positive.
+    pub fn log(start: f64, end: f64, steps: usize) -> Self {
+        assert!(start > 0.0 && end > 0.0, "Log sweeps need positive bounds.");
+        Self::from_values(spaced(start.log2(), end.log2(), steps).map(f64::exp2))
+    }
+
+    /// The given values in the given order.
+    pub fn custom(values: &[f64]) -> Self {
+        Self::from_values(values.iter().copied())
+    }
+
+    /// Append a value to the sweep.
+    pub fn with(self, value: f64) -> Self {
+        self.with_label(value, &value.to_string())
+    }
+
+    /// Append a value with a custom label to the sweep.
+    pub fn with_label(mut self, value: f64, label: &str) -> Self {
+        self.parameters.push((value, String::from(label)));
+        self
+    }
+
+    /// Returns the `(value, label)` pairs of the sweep.
+    pub fn iter(&self) -> impl Iterator<Item = (f64, &str)> {
+        self.parameters.iter().map(|(v, l)| (*v, l.as_str()))
+    }
+
+    /// Returns the values of the sweep.
+    pub fn values(&self) -> impl Iterator<Item = f64> + '_ {
+        self.parameters.iter().map(|(v, _)| *v)
+    }
+
+    fn from_values(values: impl Iterator<Item = f64>) -> Self {
+        values.fold(Self::default(), Self::with)
+    }
+}
+
+fn spaced(start: f64, end: f64, steps: usize) -> impl Iterator<Item = f64> {
+    let step = if steps > 1 {
+        (end - start) / (steps - 1) as f64
+    } else {
+        0.0
+    };
+    (0..steps).map(move |i| start + i as f64 * step)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_log_sweep() {
+        let values: Vec<f64> = ParameterSweep::log(0.25, 0.03125, 4).values().collect();
+        assert_eq!(values, [1.0 / 4.0, 1.0 / 8.0, 1.0 / 16.0, 1.0 / 32.0]);
+
+        let values: Vec<f64> = ParameterSweep::log(0.03125, 0.25, 4).values().collect();
+        assert_eq!(values, [1.0 / 32.0, 1.0 / 16.0, 1.0 / 8.0, 1.0 / 4.0]);
+    }
+
+    #[test]
+    fn test_linear_sweep() {
+        let values: Vec<f64> = ParameterSweep::linear(0.0, 1.0, 5).values().collect();
+        assert_eq!(values, [0.0, 0.25, 0.5, 0.75, 1.0]);
+
+        let values: Vec<f64> = ParameterSweep::linear(0.5, 1.0, 1).values().collect();
+        assert_eq!(values, [0.5]);
+    }
+
+    #[test]
+    fn test_labels() {
+        let sweep = ParameterSweep::custom(&[0.5, 0.125]).with_label(0.01, "1/n");
+        let parameters: Vec<(f64, &str)> = sweep.iter().collect();
+        assert_eq!(parameters, [(0.5, "0.5"), (0.125, "0.125"), (0.01, "1/n")]);
+    }
+}
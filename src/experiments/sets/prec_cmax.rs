@@ -7,14 +7,15 @@ use crate::{
     },
 };
 
-use super::{ExperimentOptions, ExperimentSet};
+use super::{ExperimentOptions, ExperimentSet, ParameterSweep};
 
 pub fn experiment_set() -> ExperimentSet {
     ExperimentSet { run, aggregate }
 }
 
-const ALGORITHMS: [prec_cmax::AlgorithmType; 3] = [
+const ALGORITHMS: [prec_cmax::AlgorithmType; 4] = [
     prec_cmax::ENUMERATE_WITH_TOPO_SORT,
+    prec_cmax::ENUMERATE_WITH_KAHN,
     prec_cmax::SOLVE_WITH_IDFS_FINISH_TIME,
     prec_cmax::SOLVE_WITH_DFS_FINISH_TIME,
 ];
@@ -33,31 +34,33 @@ fn run(options: ExperimentOptions) {
 
     for jobs in job_numbers
         .into_iter()
-        .filter(|&size| !options.max_size.is_some_and(|max| size > max))
+        .filter(|&size| options.max_size.is_none_or(|max| size <= max))
     {
+        let n = jobs as f64;
         // expected order of edges: n^2 with different constants
-        let mut edge_probabilities = vec![1.0 / 4.0, 1.0 / 8.0, 1.0 / 16.0, 1.0 / 32.0];
-        // expected order: n^1.5 = n sqrt(n)
-        edge_probabilities.push((jobs as f64).sqrt() / jobs as f64);
-        // expected order: n^1.25 = n sqrt(sqrt(n))
-        edge_probabilities.push((jobs as f64).powf(0.25) / jobs as f64);
-        // expected order: n log_2(n)
-        edge_probabilities.push((jobs as f64).log2() / jobs as f64);
-        // expected order: n log_10(n)
-        edge_probabilities.push((jobs as f64).log10() / jobs as f64);
-        // expected order: n
-        edge_probabilities.push(1.0 / jobs as f64);
+        let edge_probabilities = ParameterSweep::log(1.0 / 4.0, 1.0 / 32.0, 4)
+            // expected order: n^1.5 = n sqrt(n)
+            .with_label(n.sqrt() / n, "sqrt(n)/n")
+            // expected order: n^1.25 = n sqrt(sqrt(n))
+            .with_label(n.powf(0.25) / n, "n^0.25/n")
+            // expected order: n log_2(n)
+            .with_label(n.log2() / n, "log_2(n)/n")
+            // expected order: n log_10(n)
+            .with_label(n.log10() / n, "log_10(n)/n")
+            // expected order: n
+            .with_label(1.0 / n, "1/n");
 
-        for edge_probability in edge_probabilities {
+        for (edge_probability, label) in edge_probabilities.iter() {
             let expected_edges = jobs as f64 * jobs as f64 * edge_probability;
             if expected_edges < 1.0 || expected_edges > limit_expected_edges {
                 continue;
             }
 
             log::info!(
-                "Run 1|prec|C_max solver for {} jobs and edge probability {}.",
+                "Run 1|prec|C_max solver for {} jobs and edge probability {} ({}).",
                 jobs,
-                edge_probability
+                edge_probability,
+                label
             );
             for i in 1..=instances_per_size {
                 log::info!(
@@ -81,6 +84,6 @@ fn run(options: ExperimentOptions) {
     }
 }
 
-fn aggregate() {
-    super::aggregate::<single_machine::WithPrecedences, _, _, _>(&ALGORITHMS)
+fn aggregate(options: ExperimentOptions) {
+    super::aggregate::<single_machine::WithPrecedences, _, _, _, _>(&ALGORITHMS, options)
 }
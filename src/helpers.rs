@@ -0,0 +1,2 @@
+#[cfg(feature = "mem-profiling")]
+pub mod counting_alloc;
@@ -0,0 +1,89 @@
+//! A global allocator that counts the currently allocated and the peak number of heap bytes.
+//!
+//! Only compiled with the `mem-profiling` feature, which also installs the allocator for the whole process.
+//! The counters are global, so they only describe a single algorithm run if nothing else allocates
+//! concurrently. Peak measurements are thus only meaningful for sequential experiments, i.e. not with
+//! [`run_experiment_parallel`](crate::experiments::runner::run_experiment_parallel) and not while runs
+//! abandoned after a timeout are still running in the background.
+
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    sync::atomic::{AtomicU64, Ordering},
+};
+
+static CURRENT: AtomicU64 = AtomicU64::new(0);
+static PEAK: AtomicU64 = AtomicU64::new(0);
+
+pub struct CountingAllocator;
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            add(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            add(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        CURRENT.fetch_sub(layout.size() as u64, Ordering::Relaxed);
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = System.realloc(ptr, layout, new_size);
+        if !new_ptr.is_null() {
+            CURRENT.fetch_sub(layout.size() as u64, Ordering::Relaxed);
+            add(new_size);
+        }
+        new_ptr
+    }
+}
+
+fn add(size: usize) {
+    let current = CURRENT.fetch_add(size as u64, Ordering::Relaxed) + size as u64;
+    PEAK.fetch_max(current, Ordering::Relaxed);
+}
+
+/// The number of currently allocated bytes
+pub fn current_bytes() -> u64 {
+    CURRENT.load(Ordering::Relaxed)
+}
+
+/// The largest number of allocated bytes since the last [`reset_peak`]
+pub fn peak_bytes() -> u64 {
+    PEAK.load(Ordering::Relaxed)
+}
+
+/// Start a new peak measurement at the number of currently allocated bytes
+pub fn reset_peak() {
+    PEAK.store(CURRENT.load(Ordering::Relaxed), Ordering::Relaxed);
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_peak_bytes() {
+        let n = 1 << 20;
+        reset_peak();
+        let baseline = current_bytes();
+
+        let v: Vec<u64> = Vec::with_capacity(n);
+        drop(v);
+
+        assert!(peak_bytes() - baseline >= (n * std::mem::size_of::<u64>()) as u64);
+    }
+}
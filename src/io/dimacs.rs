@@ -0,0 +1,247 @@
+//! Reader and writer for undirected graphs in the DIMACS edge format \[1\].
+//!
+//! A DIMACS graph file contains a problem line `p edge n m` followed by `m` edge lines `e u v`
+//! with 1-indexed vertices `u` and `v`. Lines starting with `c` are comments.
+//! Edge lines may carry additional values, e.g. an edge weight, which are ignored.
+//!
+//! \[1\] “Clique and Coloring Problems Graph Format,” DIMACS Implementation Challenges, 1993. Available: <http://archive.dimacs.rutgers.edu/pub/challenge/graph/doc/ccformat.dvi>.
+
+use std::{fmt::Write as _, fs::OpenOptions, io::Write, path::Path};
+
+use crate::data_structures::graphs::{
+    DirectedAdjacencyArraysGraph, DirectedEdgeListGraph, DirectedGraph, Direction, Index,
+};
+
+use super::IOError;
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct DimacsOptions {
+    /// Graphs with more vertices than `max_size` are not read.
+    pub max_size: Option<u32>,
+}
+
+/// Read a graph in DIMACS edge format from the given file.
+///
+/// As DIMACS edge graphs are undirected, the resulting graph contains both directed arcs
+/// for each undirected edge; a loop results in a single arc.
+///
+/// Returns `Ok(None)` if the graph is larger than `options.max_size`.
+pub fn read_dimacs_from_file(
+    file_path: &Path,
+    options: DimacsOptions,
+) -> Result<Option<DirectedAdjacencyArraysGraph<u32>>, IOError> {
+    let display: String = file_path.display().to_string();
+
+    let file_content = super::read_file_to_string(file_path)?;
+
+    match parse_dimacs(&file_content, options) {
+        Err(why) => Result::Err(IOError::CannotDeserialize(display, why)),
+        Ok(graph) => Ok(graph.map(|g| DirectedAdjacencyArraysGraph::from(&g))),
+    }
+}
+
+/// Write the undirected graph in DIMACS edge format to the given file.
+///
+/// The graph is expected to contain both directed arcs for each undirected edge and a single arc per loop,
+/// as returned by [`read_dimacs_from_file`]. Each undirected edge is written once.
+pub fn write_dimacs_to_file<I: Index, G: DirectedGraph<I>>(
+    file_path: &Path,
+    graph: &G,
+) -> Result<(), IOError> {
+    super::ensure_parent_folder_exists(file_path)?;
+
+    let display: String = file_path.display().to_string();
+
+    let content = match format_dimacs(graph) {
+        Err(why) => return Result::Err(IOError::CannotSerialize(display, why)),
+        Ok(content) => content,
+    };
+
+    // open file for writing
+    let mut file = match OpenOptions::new()
+        .write(true)
+        .create(true)
+        .truncate(true)
+        .open(file_path)
+    {
+        Err(why) => return Result::Err(IOError::CannotWrite(display, why.to_string())),
+        Ok(file) => file,
+    };
+
+    match file.write_all(content.as_bytes()) {
+        Err(why) => Result::Err(IOError::CannotWrite(display, why.to_string())),
+        Ok(_) => Ok(()),
+    }
+}
+
+fn parse_dimacs(
+    content: &str,
+    options: DimacsOptions,
+) -> Result<Option<DirectedEdgeListGraph<u32>>, String> {
+    let mut lines = content
+        .lines()
+        .map(str::trim)
+        .filter(|l| !l.is_empty() && !l.starts_with('c'));
+
+    let problem = lines.next().ok_or("Missing problem line.")?;
+    let (num_vertices, num_edges) = match problem.split_whitespace().collect::<Vec<_>>()[..] {
+        ["p", "edge" | "col", n, m] => (parse_number(n)?, parse_number(m)?),
+        _ => return Err(format!("Malformed problem line {:?}.", problem)),
+    };
+    if options.max_size.is_some_and(|max| num_vertices > max) {
+        return Ok(None);
+    }
+
+    let mut edges = Vec::new();
+    let mut edges_read = 0;
+    for line in lines {
+        let (u, v) = match line.split_whitespace().collect::<Vec<_>>()[..] {
+            ["e", u, v, ..] => (parse_number(u)?, parse_number(v)?),
+            _ => return Err(format!("Malformed edge line {:?}.", line)),
+        };
+        for vertex in [u, v] {
+            if !(1..=num_vertices).contains(&vertex) {
+                return Err(format!("Edge {:?} has invalid vertex {}.", line, vertex));
+            }
+        }
+        edges.push((u - 1, v - 1));
+        if u != v {
+            edges.push((v - 1, u - 1));
+        }
+        edges_read += 1;
+    }
+
+    if edges_read != num_edges {
+        return Err(format!(
+            "Expected {} edges, found {}.",
+            num_edges, edges_read
+        ));
+    }
+
+    Ok(Some(DirectedEdgeListGraph::new(
+        num_vertices,
+        edges.into_boxed_slice(),
+    )))
+}
+
+fn format_dimacs<I: Index, G: DirectedGraph<I>>(graph: &G) -> Result<String, String> {
+    let mut arcs: Vec<(I, I)> = I::new(0)
+        .range(graph.num_vertices())
+        .flat_map(|u| graph.neighbors(u, Direction::OUT).map(move |v| (u, v)))
+        .collect();
+    let mut reversed: Vec<(I, I)> = arcs.iter().map(|&(u, v)| (v, u)).collect();
+    arcs.sort_unstable();
+    reversed.sort_unstable();
+    if arcs != reversed {
+        return Err(String::from(
+            "The graph is not undirected, i.e. not every arc has a reverse arc.",
+        ));
+    }
+
+    let edges: Vec<&(I, I)> = arcs.iter().filter(|(u, v)| u <= v).collect();
+    let mut content = format!("p edge {} {}\n", graph.num_vertices(), edges.len());
+    for (u, v) in edges {
+        writeln!(content, "e {} {}", u.index() + 1, v.index() + 1)
+            .expect("writing to a string can't fail");
+    }
+    Ok(content)
+}
+
+fn parse_number(x: &str) -> Result<u32, String> {
+    x.parse::<u32>()
+        .map_err(|why| format!("Invalid number {:?}: {}", x, why))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    // the graph of the weighted METIS test, with edge weights as third value
+    const WEIGHTED_DIMACS: &str = "\
+c weighted test graph
+p edge 7 11
+e 1 2 1
+e 1 3 2
+e 1 5 1
+e 2 3 2
+e 2 4 1
+e 3 4 2
+e 3 5 3
+e 4 6 2
+e 4 7 5
+e 5 6 3
+e 6 7 6
+";
+
+    fn sorted_arcs(graph: &DirectedAdjacencyArraysGraph<u32>) -> Vec<(u32, u32)> {
+        let mut arcs: Vec<(u32, u32)> = (0..graph.num_vertices())
+            .flat_map(|u| graph.neighbors(u, Direction::OUT).map(move |v| (u, v)))
+            .collect();
+        arcs.sort_unstable();
+        arcs
+    }
+
+    #[test]
+    fn test_parse_weighted_dimacs() {
+        let graph = parse_dimacs(WEIGHTED_DIMACS, DimacsOptions::default())
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(graph.num_vertices(), 7);
+        assert_eq!(graph.num_edges(), 22);
+        assert_eq!(graph.edges()[..4], [(0, 1), (1, 0), (0, 2), (2, 0)]);
+
+        let graph = DirectedAdjacencyArraysGraph::from(&graph);
+        let mut neighbors: Vec<u32> = graph.neighbors(3, Direction::OUT).collect();
+        neighbors.sort_unstable();
+        assert_eq!(neighbors, [1, 2, 5, 6]);
+    }
+
+    #[test]
+    fn test_dimacs_round_trip() {
+        let edges = [(0, 1), (1, 0), (1, 2), (2, 1), (3, 3), (0, 1), (1, 0)];
+        let graph =
+            DirectedAdjacencyArraysGraph::from(&DirectedEdgeListGraph::new(5, Box::new(edges)));
+
+        let file_path = std::env::temp_dir().join("exp_lib_test_dimacs_round_trip.dimacs");
+        write_dimacs_to_file(&file_path, &graph).unwrap();
+        let loaded = read_dimacs_from_file(&file_path, DimacsOptions::default());
+        std::fs::remove_file(&file_path).unwrap();
+        let loaded = loaded.unwrap().unwrap();
+
+        assert_eq!(loaded.num_vertices(), 5);
+        assert_eq!(sorted_arcs(&loaded), sorted_arcs(&graph));
+    }
+
+    #[test]
+    fn test_format_dimacs() {
+        let graph = parse_dimacs("p edge 3 2\ne 2 1\ne 3 3\n", DimacsOptions::default())
+            .unwrap()
+            .unwrap();
+        assert_eq!(format_dimacs(&graph).unwrap(), "p edge 3 2\ne 1 2\ne 3 3\n");
+
+        let directed = DirectedEdgeListGraph::new(2, Box::new([(0, 1)]));
+        assert!(format_dimacs(&directed).is_err());
+    }
+
+    #[test]
+    fn test_parse_dimacs_max_size() {
+        let graph = parse_dimacs(WEIGHTED_DIMACS, DimacsOptions { max_size: Some(6) }).unwrap();
+        assert!(graph.is_none());
+    }
+
+    #[test]
+    fn test_parse_dimacs_errors() {
+        // wrong number of edges
+        assert!(parse_dimacs("p edge 2 2\ne 1 2\n", DimacsOptions::default()).is_err());
+        assert!(parse_dimacs("p edge 2 4294967295\ne 1 2\n", DimacsOptions::default()).is_err());
+        // vertex out of bounds
+        assert!(parse_dimacs("p edge 2 1\ne 1 3\n", DimacsOptions::default()).is_err());
+        // 0 is no vertex as vertices are 1-indexed
+        assert!(parse_dimacs("p edge 2 1\ne 0 1\n", DimacsOptions::default()).is_err());
+        // missing problem line
+        assert!(parse_dimacs("e 1 2\n", DimacsOptions::default()).is_err());
+        // unknown line
+        assert!(parse_dimacs("p edge 2 1\na 1 2\n", DimacsOptions::default()).is_err());
+    }
+}
@@ -0,0 +1,208 @@
+//! Reader for graphs stored as plain text edge lists.
+//!
+//! Each non-empty line that is not a comment contains one edge `u v`, optionally followed by a weight,
+//! separated by whitespace. The number of vertices is derived from the largest vertex id.
+
+use std::path::Path;
+
+use crate::data_structures::graphs::{DirectedAdjacencyArraysGraph, DirectedEdgeListGraph};
+
+use super::IOError;
+
+#[derive(Debug, Clone, Copy)]
+pub struct EdgeListOptions {
+    /// Graphs with more vertices than `max_size` are not read.
+    pub max_size: Option<u32>,
+    /// Whether the vertex ids start at 1 instead of 0.
+    pub one_indexed: bool,
+    /// Whether each edge is followed by a weight.
+    pub weighted: bool,
+    /// Lines starting with this character are skipped.
+    pub comment_prefix: char,
+    /// Whether each line is a directed edge or an undirected edge, which is stored as both directed arcs.
+    pub directed: bool,
+}
+
+impl Default for EdgeListOptions {
+    fn default() -> Self {
+        Self {
+            max_size: None,
+            one_indexed: false,
+            weighted: false,
+            comment_prefix: '#',
+            directed: true,
+        }
+    }
+}
+
+/// Read a graph stored as edge list from the given file.
+///
+/// Undirected edges result in both directed arcs, loops in a single arc.
+/// Weights are validated to be numbers, but not stored, as our graph types do not carry edge data.
+///
+/// Returns `Ok(None)` if the graph is larger than `options.max_size`.
+pub fn read_edge_list_from_file(
+    file_path: &Path,
+    options: EdgeListOptions,
+) -> Result<Option<DirectedAdjacencyArraysGraph<u32>>, IOError> {
+    let display: String = file_path.display().to_string();
+
+    let file_content = super::read_file_to_string(file_path)?;
+
+    match parse_edge_list(&file_content, options) {
+        Err(why) => Result::Err(IOError::CannotDeserialize(display, why)),
+        Ok(graph) => Ok(graph.map(|g| DirectedAdjacencyArraysGraph::from(&g))),
+    }
+}
+
+fn parse_edge_list(
+    content: &str,
+    options: EdgeListOptions,
+) -> Result<Option<DirectedEdgeListGraph<u32>>, String> {
+    let first_vertex = u32::from(options.one_indexed);
+    let columns = if options.weighted { 3 } else { 2 };
+
+    let mut edges = Vec::new();
+    let mut num_vertices = 0;
+    for line in content
+        .lines()
+        .map(str::trim)
+        .filter(|l| !l.is_empty() && !l.starts_with(options.comment_prefix))
+    {
+        let values: Vec<&str> = line.split_whitespace().collect();
+        if values.len() != columns {
+            return Err(format!("Expected {} columns, found {:?}.", columns, line));
+        }
+        if options.weighted {
+            values[2]
+                .parse::<f64>()
+                .map_err(|why| format!("Invalid weight {:?}: {}", values[2], why))?;
+        }
+
+        let [u, v] = [values[0], values[1]].map(|x| {
+            let vertex = x
+                .parse::<u32>()
+                .map_err(|why| format!("Invalid vertex {:?}: {}", x, why))?;
+            vertex
+                .checked_sub(first_vertex)
+                .ok_or_else(|| format!("Invalid vertex {} in 1-indexed edge list.", vertex))
+        });
+        let (u, v) = (u?, v?);
+
+        let required_vertices = u
+            .max(v)
+            .checked_add(1)
+            .ok_or_else(|| format!("Too many vertices for edge {:?}.", line))?;
+        num_vertices = num_vertices.max(required_vertices);
+        if options.max_size.is_some_and(|max| num_vertices > max) {
+            return Ok(None);
+        }
+
+        edges.push((u, v));
+        if !options.directed && u != v {
+            edges.push((v, u));
+        }
+    }
+
+    Ok(Some(DirectedEdgeListGraph::new(
+        num_vertices,
+        edges.into_boxed_slice(),
+    )))
+}
+
+#[cfg(test)]
+mod test {
+    use crate::data_structures::graphs::{DirectedGraph, Direction};
+
+    use super::*;
+
+    const WEIGHTED_EDGE_LIST: &str = "\
+# weighted, 1-indexed path 1 - 2 - 3 with a loop at 4
+1 2 0.5
+# the second edge
+2\t3   7
+
+4 4 1e3
+";
+
+    const UNWEIGHTED_EDGE_LIST: &str = "0 1\n1 2\n2 0\n0 4\n";
+
+    #[test]
+    fn test_parse_weighted_one_indexed() {
+        let options = EdgeListOptions {
+            one_indexed: true,
+            weighted: true,
+            directed: false,
+            ..Default::default()
+        };
+        let graph = parse_edge_list(WEIGHTED_EDGE_LIST, options)
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(graph.num_vertices(), 4);
+        assert_eq!(graph.edges(), [(0, 1), (1, 0), (1, 2), (2, 1), (3, 3)]);
+    }
+
+    #[test]
+    fn test_parse_unweighted_zero_indexed() {
+        let graph = parse_edge_list(UNWEIGHTED_EDGE_LIST, EdgeListOptions::default())
+            .unwrap()
+            .unwrap();
+
+        // vertex 3 has no edges
+        assert_eq!(graph.num_vertices(), 5);
+        assert_eq!(graph.edges(), [(0, 1), (1, 2), (2, 0), (0, 4)]);
+
+        let graph = DirectedAdjacencyArraysGraph::from(&graph);
+        assert!(graph.neighbors(0, Direction::OUT).eq([1, 4]));
+        assert!(graph.neighbors(0, Direction::IN).eq([2]));
+    }
+
+    #[test]
+    fn test_read_edge_list_from_file() {
+        let file_path = std::env::temp_dir().join("exp_lib_test_edge_list.edges");
+        std::fs::write(&file_path, UNWEIGHTED_EDGE_LIST).unwrap();
+        let graph = read_edge_list_from_file(&file_path, EdgeListOptions::default());
+        std::fs::remove_file(&file_path).unwrap();
+
+        let graph = graph.unwrap().unwrap();
+        assert_eq!(graph.num_vertices(), 5);
+        assert_eq!(graph.num_edges(), 4);
+    }
+
+    #[test]
+    fn test_parse_edge_list_max_size() {
+        let options = EdgeListOptions {
+            max_size: Some(4),
+            ..Default::default()
+        };
+        assert!(parse_edge_list(UNWEIGHTED_EDGE_LIST, options)
+            .unwrap()
+            .is_none());
+    }
+
+    #[test]
+    fn test_parse_edge_list_errors() {
+        let one_indexed = EdgeListOptions {
+            one_indexed: true,
+            ..Default::default()
+        };
+        let weighted = EdgeListOptions {
+            weighted: true,
+            ..Default::default()
+        };
+
+        // 0 is no vertex in 1-indexed lists
+        assert!(parse_edge_list("0 1\n", one_indexed).is_err());
+        // missing or unexpected weight
+        assert!(parse_edge_list("0 1\n", weighted).is_err());
+        assert!(parse_edge_list("0 1 2\n", EdgeListOptions::default()).is_err());
+        // invalid numbers
+        assert!(parse_edge_list("0 1 heavy\n", weighted).is_err());
+        assert!(parse_edge_list("0 -1\n", EdgeListOptions::default()).is_err());
+        // vertex count does not fit the index type
+        assert!(parse_edge_list("4294967295 0\n", EdgeListOptions::default()).is_err());
+        // comments use a different prefix
+        assert!(parse_edge_list("% comment\n0 1\n", EdgeListOptions::default()).is_err());
+    }
+}
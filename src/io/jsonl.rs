@@ -0,0 +1,120 @@
+//! JSON Lines files, i.e. one json object per line.
+
+use std::{
+    fs::OpenOptions,
+    io::{BufRead, BufReader, BufWriter, Write},
+    path::Path,
+};
+
+use serde::{de::DeserializeOwned, Serialize};
+
+use super::{ensure_parent_folder_exists, IOError};
+
+/// Append the given objects to the file, one json object per line.
+pub fn append_jsonl_to_file<T: Serialize>(file_path: &Path, objects: &[T]) -> Result<(), IOError> {
+    ensure_parent_folder_exists(file_path)?;
+
+    let display: String = file_path.display().to_string();
+
+    // open file for appending
+    let file = match OpenOptions::new().append(true).create(true).open(file_path) {
+        Err(why) => return Result::Err(IOError::CannotWrite(display, why.to_string())),
+        Ok(file) => file,
+    };
+
+    let mut writer = BufWriter::new(file);
+    for object in objects {
+        serde_json::to_writer(&mut writer, object)
+            .map_err(|why| IOError::CannotSerialize(display.clone(), why.to_string()))?;
+        writer
+            .write_all(b"\n")
+            .map_err(|why| IOError::CannotWrite(display.clone(), why.to_string()))?;
+    }
+    writer
+        .flush()
+        .map_err(|why| IOError::CannotWrite(display, why.to_string()))
+}
+
+/// Read all lines of the file that can be deserialized, skipping empty lines.
+pub fn read_jsonl_from_file<T: DeserializeOwned>(file_path: &Path) -> Result<Vec<T>, IOError> {
+    Ok(stream_jsonl_from_file(file_path)?
+        .filter_map(Result::ok)
+        .collect())
+}
+
+/// Lazily read the lines of the file, skipping empty lines.
+///
+/// Lines that can't be read or deserialized are reported as errors.
+pub fn stream_jsonl_from_file<T: DeserializeOwned>(
+    file_path: &Path,
+) -> Result<impl Iterator<Item = Result<T, IOError>>, IOError> {
+    let display: String = file_path.display().to_string();
+
+    // open file for reading
+    let file = match OpenOptions::new().read(true).open(file_path) {
+        Err(why) => return Result::Err(IOError::CannotRead(display, why.to_string())),
+        Ok(file) => file,
+    };
+
+    Ok(BufReader::new(file)
+        .lines()
+        .filter(|line| line.as_ref().map_or(true, |l| !l.trim().is_empty()))
+        .map(move |line| {
+            let line = line.map_err(|why| IOError::CannotRead(display.clone(), why.to_string()))?;
+            serde_json::from_str(&line)
+                .map_err(|why| IOError::CannotDeserialize(display.clone(), why.to_string()))
+        }))
+}
+
+#[cfg(test)]
+mod test {
+    use crate::experiments::EnumerationMeasurement;
+
+    use super::*;
+
+    fn measurement(total_time: u64) -> EnumerationMeasurement {
+        EnumerationMeasurement {
+            total_time,
+            preprocessing: 10,
+            first_output: 20,
+            delays: 3,
+            delay_min: 5,
+            delay_max: 50,
+            delay_avg: 25.5,
+            delay_variance: 0.25,
+            peak_bytes: None,
+            failed: false,
+        }
+    }
+
+    #[test]
+    fn test_jsonl_round_trip() {
+        let file_path = std::env::temp_dir().join("exp_lib_test_jsonl_round_trip.jsonl");
+        let _ = std::fs::remove_file(&file_path);
+
+        append_jsonl_to_file(&file_path, &[measurement(1), measurement(2)]).unwrap();
+        append_jsonl_to_file(&file_path, &[measurement(3)]).unwrap();
+
+        let content = std::fs::read_to_string(&file_path).unwrap();
+        let read: Vec<EnumerationMeasurement> = read_jsonl_from_file(&file_path).unwrap();
+        std::fs::remove_file(&file_path).unwrap();
+
+        assert_eq!(content.lines().count(), 3);
+        assert!(content.starts_with("{\"total_time\":1,"));
+        assert_eq!(read, [1, 2, 3].map(measurement));
+    }
+
+    #[test]
+    fn test_jsonl_invalid_line() {
+        let file_path = std::env::temp_dir().join("exp_lib_test_jsonl_invalid_line.jsonl");
+        std::fs::write(&file_path, "{\"a\":1}\n\nnot json\n{\"a\":2}\n").unwrap();
+
+        let rows: Vec<Result<serde_json::Value, IOError>> =
+            stream_jsonl_from_file(&file_path).unwrap().collect();
+        std::fs::remove_file(&file_path).unwrap();
+
+        assert_eq!(rows.len(), 3);
+        assert!(matches!(rows[1], Err(IOError::CannotDeserialize(_, _))));
+        assert_eq!(rows[2].as_ref().unwrap()["a"], 2);
+    }
+}
@@ -0,0 +1,227 @@
+//! Reader for graphs in the METIS format \[1\].
+//!
+//! A METIS graph file starts with a header line `n m [fmt [ncon]]` followed by one line per vertex
+//! listing the (1-indexed) neighbors of the vertex. Lines starting with `%` are comments.
+//! The optional three-digit `fmt` flag states whether each vertex line starts with a vertex size (first digit),
+//! `ncon` vertex weights (second digit), and whether each neighbor is followed by an edge weight (third digit).
+//!
+//! \[1\] G. Karypis, “METIS: A Software Package for Partitioning Unstructured Graphs, Partitioning Meshes, and Computing Fill-Reducing Orderings of Sparse Matrices,” Version 5.1.0, University of Minnesota, 2013. Available: <https://github.com/KarypisLab/METIS>.
+
+use std::path::Path;
+
+use crate::data_structures::graphs::{DirectedAdjacencyArraysGraph, DirectedEdgeListGraph};
+
+use super::IOError;
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct MetisOptions {
+    /// Graphs with more vertices than `max_size` are not read.
+    pub max_size: Option<u32>,
+}
+
+/// Read a graph in METIS format from the given file.
+///
+/// As METIS graphs are undirected and list each edge for both of its vertices,
+/// the resulting graph contains both directed arcs for each undirected edge.
+/// Vertex sizes, vertex weights and edge weights are validated, but not stored,
+/// as our graph types do not carry vertex or edge data.
+/// Files in which an edge is not listed for both of its vertices with the same weight are rejected.
+///
+/// Returns `Ok(None)` if the graph is larger than `options.max_size`.
+pub fn read_metis_from_file(
+    file_path: &Path,
+    options: MetisOptions,
+) -> Result<Option<DirectedAdjacencyArraysGraph<u32>>, IOError> {
+    let display: String = file_path.display().to_string();
+
+    let file_content = super::read_file_to_string(file_path)?;
+
+    match parse_metis(&file_content, options) {
+        Err(why) => Result::Err(IOError::CannotDeserialize(display, why)),
+        Ok(graph) => Ok(graph.map(|g| DirectedAdjacencyArraysGraph::from(&g))),
+    }
+}
+
+fn parse_metis(
+    content: &str,
+    options: MetisOptions,
+) -> Result<Option<DirectedEdgeListGraph<u32>>, String> {
+    let mut lines = content.lines().filter(|l| !l.starts_with('%'));
+
+    let header = lines.next().ok_or("Missing header line.")?;
+    let header: Vec<u32> = parse_numbers(header)?;
+    let (num_vertices, num_edges) = match header[..] {
+        [n, m, ..] if header.len() <= 4 => (n, m),
+        _ => return Err(format!("Malformed header line {:?}.", header)),
+    };
+    if options.max_size.is_some_and(|max| num_vertices > max) {
+        return Ok(None);
+    }
+
+    let format = header.get(2).copied().unwrap_or(0);
+    if format > 111 || format % 10 > 1 || format / 10 % 10 > 1 {
+        return Err(format!("Unknown format flag {:03}.", format));
+    }
+    let has_vertex_sizes = format / 100 == 1;
+    let has_vertex_weights = format / 10 % 10 == 1;
+    let has_edge_weights = format % 10 == 1;
+    let num_vertex_weights = match header.get(3) {
+        Some(&ncon) if has_vertex_weights => ncon as usize,
+        Some(_) => {
+            return Err(String::from(
+                "Number of vertex weights given without vertex weights.",
+            ))
+        }
+        None if has_vertex_weights => 1,
+        None => 0,
+    };
+    let num_vertex_values = usize::from(has_vertex_sizes) + num_vertex_weights;
+    let neighbor_stride = if has_edge_weights { 2 } else { 1 };
+
+    let mut weighted_edges = Vec::new();
+    for u in 0..num_vertices {
+        let line = lines.next().ok_or(format!(
+            "Expected {} vertex lines, found {}.",
+            num_vertices, u
+        ))?;
+        let values = parse_numbers(line)?;
+        if values.len() < num_vertex_values
+            || (values.len() - num_vertex_values) % neighbor_stride != 0
+        {
+            return Err(format!("Malformed line for vertex {}.", u + 1));
+        }
+        for adjacency in values[num_vertex_values..].chunks(neighbor_stride) {
+            let neighbor = adjacency[0];
+            if !(1..=num_vertices).contains(&neighbor) {
+                return Err(format!(
+                    "Vertex {} has invalid neighbor {}.",
+                    u + 1,
+                    neighbor
+                ));
+            }
+            weighted_edges.push((u, neighbor - 1, adjacency.get(1).copied()));
+        }
+    }
+
+    if lines.any(|l| !l.trim().is_empty()) {
+        return Err(format!(
+            "Expected {} vertex lines, found more.",
+            num_vertices
+        ));
+    }
+    if weighted_edges.len() != 2 * num_edges as usize {
+        return Err(format!(
+            "Expected {} edges, found {} adjacencies.",
+            num_edges,
+            weighted_edges.len()
+        ));
+    }
+
+    check_symmetry(&weighted_edges)?;
+    let edges: Vec<(u32, u32)> = weighted_edges.into_iter().map(|(u, v, _)| (u, v)).collect();
+
+    Ok(Some(DirectedEdgeListGraph::new(
+        num_vertices,
+        edges.into_boxed_slice(),
+    )))
+}
+
+/// Checks that each edge `(u, v)` is listed for `v` as well, with the same weight.
+fn check_symmetry(edges: &[(u32, u32, Option<u32>)]) -> Result<(), String> {
+    let mut edges = edges.to_vec();
+    let mut reversed: Vec<(u32, u32, Option<u32>)> =
+        edges.iter().map(|&(u, v, w)| (v, u, w)).collect();
+    edges.sort_unstable();
+    reversed.sort_unstable();
+
+    match edges.iter().zip(&reversed).find(|(e, r)| e != r) {
+        None => Ok(()),
+        Some((&(u, v, _), _)) => Err(format!(
+            "Adjacencies are not symmetric: edge {{{}, {}}} is listed differently for its vertices.",
+            u + 1,
+            v + 1
+        )),
+    }
+}
+
+fn parse_numbers(line: &str) -> Result<Vec<u32>, String> {
+    line.split_whitespace()
+        .map(|x| {
+            x.parse::<u32>()
+                .map_err(|why| format!("Invalid number {:?}: {}", x, why))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use crate::data_structures::graphs::{DirectedGraph, Direction};
+
+    use super::*;
+
+    // 7 vertices, 11 edges, 2 weights per vertex and edge weights
+    const WEIGHTED_METIS: &str = "\
+% weighted test graph
+7 11 011 2
+4 1 2 1 3 2 5 1
+2 2 1 1 3 2 4 1
+5 3 1 2 2 2 4 2 5 3
+1 1 2 1 3 2 6 2 7 5
+3 2 1 1 3 3 6 3
+2 2 4 2 5 3 7 6
+1 3 4 5 6 6
+";
+
+    #[test]
+    fn test_parse_weighted_metis() {
+        let graph = parse_metis(WEIGHTED_METIS, MetisOptions::default())
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(graph.num_vertices(), 7);
+        assert_eq!(graph.num_edges(), 22);
+        assert_eq!(
+            graph.edges()[..4],
+            [(0, 1), (0, 2), (0, 4), (1, 0)],
+            "Neighbors of the first vertices don't match."
+        );
+
+        let graph = DirectedAdjacencyArraysGraph::from(&graph);
+        let mut neighbors: Vec<u32> = graph.neighbors(3, Direction::OUT).collect();
+        neighbors.sort_unstable();
+        assert_eq!(neighbors, [1, 2, 5, 6]);
+    }
+
+    #[test]
+    fn test_parse_metis_max_size() {
+        let graph = parse_metis(WEIGHTED_METIS, MetisOptions { max_size: Some(6) }).unwrap();
+        assert!(graph.is_none());
+    }
+
+    #[test]
+    fn test_parse_metis_errors() {
+        // wrong number of edges
+        assert!(parse_metis("2 2\n2\n1\n", MetisOptions::default()).is_err());
+        assert!(parse_metis("2 4294967295\n2\n1\n", MetisOptions::default()).is_err());
+        // neighbor out of bounds
+        assert!(parse_metis("2 1\n3\n1\n", MetisOptions::default()).is_err());
+        // missing edge weight
+        assert!(parse_metis("2 1 001\n2 1\n1\n", MetisOptions::default()).is_err());
+        // missing vertex line
+        assert!(parse_metis("3 1\n2\n1\n", MetisOptions::default()).is_err());
+    }
+
+    #[test]
+    fn test_parse_metis_asymmetric() {
+        // 1 lists 2, but 2 lists 3 instead of 1
+        let asymmetric = parse_metis("3 1\n2\n3\n\n", MetisOptions::default());
+        assert!(asymmetric.unwrap_err().contains("not symmetric"));
+
+        // edge {1, 2} has different weights for its vertices
+        let asymmetric = parse_metis("2 1 001\n2 5\n1 6\n", MetisOptions::default());
+        assert!(asymmetric.unwrap_err().contains("not symmetric"));
+
+        let symmetric = parse_metis("2 1 001\n2 5\n1 5\n", MetisOptions::default());
+        assert!(symmetric.unwrap().is_some());
+    }
+}
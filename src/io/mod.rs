@@ -1,9 +1,15 @@
+pub mod dimacs;
+pub mod edge_list;
+pub mod jsonl;
+pub mod metis;
+
+use flate2::{read::MultiGzDecoder, write::GzEncoder, Compression};
 use serde::de::DeserializeOwned;
 use serde::Serialize;
 use std::fmt;
-use std::fs::{create_dir_all, OpenOptions};
-use std::io::{BufWriter, ErrorKind, Read};
-use std::path::Path;
+use std::fs::{create_dir_all, File, OpenOptions};
+use std::io::{BufWriter, ErrorKind, Read, Write};
+use std::path::{Path, PathBuf};
 
 #[derive(Debug)]
 pub enum IOError {
@@ -40,7 +46,12 @@ pub fn write_json_to_file<T: Serialize>(file_path: &Path, object: T) -> Result<(
     let display: String = file_path.display().to_string();
 
     // open file for writing
-    let file = match OpenOptions::new().write(true).create(true).open(file_path) {
+    let file = match OpenOptions::new()
+        .write(true)
+        .create(true)
+        .truncate(true)
+        .open(file_path)
+    {
         Err(why) => return Result::Err(IOError::CannotWrite(display, why.to_string())),
         Ok(file) => file,
     };
@@ -55,16 +66,7 @@ pub fn write_json_to_file<T: Serialize>(file_path: &Path, object: T) -> Result<(
 pub fn read_json_from_file<T: DeserializeOwned>(file_path: &Path) -> Result<T, IOError> {
     let display: String = file_path.display().to_string();
 
-    // open file for reading
-    let mut file = match OpenOptions::new().read(true).open(file_path) {
-        Err(why) => return Result::Err(IOError::CannotRead(display, why.to_string())),
-        Ok(file) => file,
-    };
-
-    let mut file_content = String::new();
-    if let Err(why) = file.read_to_string(&mut file_content) {
-        return Result::Err(IOError::CannotRead(display, why.to_string()));
-    }
+    let file_content = read_file_to_string(file_path)?;
 
     // load object from file
     match serde_json::from_str(&file_content) {
@@ -73,50 +75,355 @@ pub fn read_json_from_file<T: DeserializeOwned>(file_path: &Path) -> Result<T, I
     }
 }
 
+/// How the header row of a new csv file is determined.
+#[derive(Debug, Clone, Default)]
+pub enum HeaderMode {
+    /// Use the field names of the serialized objects.
+    #[default]
+    Auto,
+    /// Use the given names; there has to be exactly one name per serialized field.
+    Named(Vec<String>),
+}
+
+/// Options for reading and writing csv files.
+#[derive(Debug, Clone)]
+pub struct CsvOptions {
+    /// Round all fields holding decimal numbers to this many significant digits (`None` keeps full precision).
+    ///
+    /// A field is considered to hold a decimal number if it parses as `f64` and contains a decimal point or exponent,
+    /// so integer fields are never changed. Only used for writing.
+    pub float_precision: Option<usize>,
+    /// The field delimiter, `b','` by default
+    pub delimiter: u8,
+    /// The quote character, `b'"'` by default
+    pub quote: u8,
+    /// Whether records may have different numbers of fields, `false` by default
+    pub flexible: bool,
+}
+
+impl Default for CsvOptions {
+    fn default() -> Self {
+        Self {
+            float_precision: None,
+            delimiter: b',',
+            quote: b'"',
+            flexible: false,
+        }
+    }
+}
+
+impl CsvOptions {
+    fn writer_builder(&self) -> csv::WriterBuilder {
+        let mut builder = csv::WriterBuilder::new();
+        builder
+            .delimiter(self.delimiter)
+            .quote(self.quote)
+            .flexible(self.flexible);
+        builder
+    }
+
+    fn reader_builder(&self) -> csv::ReaderBuilder {
+        let mut builder = csv::ReaderBuilder::new();
+        builder
+            .delimiter(self.delimiter)
+            .quote(self.quote)
+            .flexible(self.flexible);
+        builder
+    }
+}
+
+/// Append the given objects to the csv file, writing a header with the field names if the file is new.
+///
+/// Files whose name ends in `.gz` are gzip-compressed; every append adds a separate gzip member.
+/// [`read_csv_from_file`] reads such files transparently.
 pub fn append_csv_to_file<T: Serialize>(file_path: &Path, objects: &[T]) -> Result<(), IOError> {
+    append_csv_to_file_with_header_mode(file_path, objects, &HeaderMode::Auto)
+}
+
+/// Append the given objects to the csv file, writing a header according to `header_mode` if the file is new.
+pub fn append_csv_to_file_with_header_mode<T: Serialize>(
+    file_path: &Path,
+    objects: &[T],
+    header_mode: &HeaderMode,
+) -> Result<(), IOError> {
+    append_csv_to_file_with_options(file_path, objects, header_mode, &CsvOptions::default())
+}
+
+/// Append the given objects to the csv file according to `options`, writing a header according to `header_mode`
+/// if the file is new.
+///
+/// Fails without writing anything if the file already has a different header than `header_mode` would write.
+pub fn append_csv_to_file_with_options<T: Serialize>(
+    file_path: &Path,
+    objects: &[T],
+    header_mode: &HeaderMode,
+    options: &CsvOptions,
+) -> Result<(), IOError> {
     ensure_parent_folder_exists(file_path)?;
 
     let display: String = file_path.display().to_string();
 
-    let mut writer;
+    if let (HeaderMode::Named(names), Some(object)) = (header_mode, objects.first()) {
+        let num_fields = count_csv_fields(object)
+            .map_err(|why| IOError::CannotSerialize(display.clone(), why.to_string()))?;
+        if num_fields != names.len() {
+            return Result::Err(IOError::CannotSerialize(
+                display,
+                format!(
+                    "Got {} header names for {} fields.",
+                    names.len(),
+                    num_fields
+                ),
+            ));
+        }
+    }
+
+    // only append rows that match the header of an existing file
+    let has_content = file_path.metadata().is_ok_and(|m| m.len() > 0);
+    if let (true, Some(object)) = (has_content, objects.first()) {
+        let header = read_csv_header(file_path, options)?;
+        ensure_header_matches(&header, object, header_mode, &display)?;
+    }
 
-    match OpenOptions::new()
+    // open a new file or append to an existing one that already has a header
+    let (file, is_new_file) = match OpenOptions::new()
         .write(true)
         .create_new(true)
         .open(file_path)
     {
-        Ok(file) => {
-            // write to new file, including headers
-            writer = csv::WriterBuilder::new()
-                .has_headers(true)
-                .from_writer(file);
-        }
+        Ok(file) => (file, true),
         Err(why) => match why.kind() {
             ErrorKind::AlreadyExists => {
                 match OpenOptions::new().append(true).create(true).open(file_path) {
-                    Ok(file) => {
-                        // append to existing file that already has headers
-                        writer = csv::WriterBuilder::new()
-                            .has_headers(false)
-                            .from_writer(file);
-                    }
+                    Ok(file) => (file, false),
                     Err(why) => return Result::Err(IOError::CannotWrite(display, why.to_string())),
-                };
+                }
             }
             _ => {
                 return Result::Err(IOError::CannotWrite(display, why.to_string()));
             }
         },
+    };
+
+    if is_gzip(file_path) {
+        // appending adds another gzip member, which is read as continuation of the previous ones
+        let mut encoder = GzEncoder::new(file, Compression::default());
+        write_csv_records(
+            &mut encoder,
+            objects,
+            header_mode,
+            is_new_file,
+            options,
+            &display,
+        )?;
+        encoder
+            .finish()
+            .map_err(|why| IOError::CannotWrite(display, why.to_string()))?;
+        Ok(())
+    } else {
+        write_csv_records(file, objects, header_mode, is_new_file, options, &display)
     }
-    for object in objects {
+}
+
+/// Whether the file is gzip-compressed, i.e. whether its name ends in `.gz`.
+fn is_gzip(file_path: &Path) -> bool {
+    file_path.extension().is_some_and(|e| e == "gz")
+}
+
+/// Write the objects as csv records to `sink`, preceded by a header according to `header_mode` for new files.
+fn write_csv_records<T: Serialize, W: Write>(
+    sink: W,
+    objects: &[T],
+    header_mode: &HeaderMode,
+    is_new_file: bool,
+    options: &CsvOptions,
+    display: &str,
+) -> Result<(), IOError> {
+    // whether the field names still have to be written as header
+    let mut needs_auto_header = is_new_file && matches!(header_mode, HeaderMode::Auto);
+    let mut writer = options
+        .writer_builder()
+        .has_headers(needs_auto_header)
+        .from_writer(sink);
+
+    if let (HeaderMode::Named(names), true) = (header_mode, is_new_file) {
         writer
-            .serialize(object)
-            .map_err(|why| IOError::CannotSerialize(display.clone(), why.to_string()))?
+            .write_record(names)
+            .map_err(|why| IOError::CannotWrite(String::from(display), why.to_string()))?;
+    }
+    for object in objects {
+        match options.float_precision {
+            None => writer.serialize(object),
+            Some(precision) => serialize_to_records(object).and_then(|(header, record)| {
+                if let (true, Some(header)) = (needs_auto_header, header) {
+                    writer.write_record(&header)?;
+                }
+                needs_auto_header = false;
+                writer.write_record(
+                    record
+                        .iter()
+                        .map(|field| round_decimal_field(field, precision)),
+                )
+            }),
+        }
+        .map_err(|why| IOError::CannotSerialize(String::from(display), why.to_string()))?
+    }
+    writer
+        .flush()
+        .map_err(|why| IOError::CannotWrite(String::from(display), why.to_string()))
+}
+
+/// Serialize the given object to a csv record, together with the field names if it has any.
+fn serialize_to_records<T: Serialize>(
+    object: &T,
+) -> Result<(Option<csv::StringRecord>, csv::StringRecord), csv::Error> {
+    let mut writer = csv::WriterBuilder::new()
+        .has_headers(true)
+        .from_writer(Vec::new());
+    writer.serialize(object)?;
+    let records = writer.into_inner().map_err(|e| e.into_error())?;
+
+    let mut reader = csv::ReaderBuilder::new()
+        .has_headers(false)
+        .flexible(true)
+        .from_reader(records.as_slice());
+    let mut records = reader.records().collect::<Result<Vec<_>, _>>()?;
+    let record = records.pop().unwrap_or_default();
+    Ok((records.pop(), record))
+}
+
+/// Round the field to `precision` significant digits if it holds a decimal number.
+fn round_decimal_field(field: &str, precision: usize) -> String {
+    let is_decimal = field.contains(['.', 'e', 'E']);
+    match field.parse::<f64>() {
+        Ok(value) if is_decimal && value.is_finite() => {
+            let rounded = format!("{:.*e}", precision.saturating_sub(1), value);
+            rounded.parse::<f64>().unwrap_or(value).to_string()
+        }
+        _ => String::from(field),
+    }
+}
+
+/// Read the header row of the csv file according to `options`, decompressing files whose name ends in `.gz`.
+fn read_csv_header(file_path: &Path, options: &CsvOptions) -> Result<csv::StringRecord, IOError> {
+    let display: String = file_path.display().to_string();
+
+    let file = match OpenOptions::new().read(true).open(file_path) {
+        Err(why) => return Result::Err(IOError::CannotRead(display, why.to_string())),
+        Ok(file) => file,
+    };
+    let file: Box<dyn Read> = if is_gzip(file_path) {
+        Box::new(MultiGzDecoder::new(file))
+    } else {
+        Box::new(file)
+    };
+
+    options
+        .reader_builder()
+        .has_headers(true)
+        .from_reader(file)
+        .headers()
+        .cloned()
+        .map_err(|why| IOError::CannotRead(display, why.to_string()))
+}
+
+/// Ensure that the object's fields can be appended below the existing `header`,
+/// i.e. that the header is the one `header_mode` would write for the object.
+///
+/// Objects without field names (e.g. tuples) only need to have one field per header column in `Auto` mode.
+fn ensure_header_matches<T: Serialize>(
+    header: &csv::StringRecord,
+    object: &T,
+    header_mode: &HeaderMode,
+    display: &str,
+) -> Result<(), IOError> {
+    let (names, record) = serialize_to_records(object)
+        .map_err(|why| IOError::CannotSerialize(String::from(display), why.to_string()))?;
+    let expected = match header_mode {
+        HeaderMode::Named(names) => csv::StringRecord::from(names.clone()),
+        HeaderMode::Auto => match names {
+            Some(names) => names,
+            None if record.len() == header.len() => return Ok(()),
+            None => record,
+        },
+    };
+    if expected == *header {
+        return Ok(());
     }
-    Ok(())
+
+    Result::Err(IOError::CannotSerialize(
+        String::from(display),
+        format!(
+            "The header '{}' doesn't match the fields '{}' of the new rows.",
+            header.iter().collect::<Vec<_>>().join(","),
+            expected.iter().collect::<Vec<_>>().join(","),
+        ),
+    ))
 }
 
+/// Count the number of csv fields the given object serializes to.
+fn count_csv_fields<T: Serialize>(object: &T) -> Result<usize, csv::Error> {
+    let mut writer = csv::WriterBuilder::new()
+        .has_headers(false)
+        .from_writer(Vec::new());
+    writer.serialize(object)?;
+    let record = writer.into_inner().map_err(|e| e.into_error())?;
+
+    let mut reader = csv::ReaderBuilder::new()
+        .has_headers(false)
+        .from_reader(record.as_slice());
+    let mut fields = csv::ByteRecord::new();
+    reader.read_byte_record(&mut fields)?;
+    Ok(fields.len())
+}
+
+/// Read all rows of the csv file that can be deserialized like [`read_csv_from_file_with_options`],
+/// decompressing files whose name ends in `.gz`.
 pub fn read_csv_from_file<T: DeserializeOwned>(file_path: &Path) -> Result<Vec<T>, IOError> {
+    read_csv_from_file_with_options(file_path, &CsvOptions::default())
+}
+
+/// Read all rows of the csv file that can be deserialized according to `options`,
+/// decompressing files whose name ends in `.gz`.
+///
+/// Rows that can't be deserialized are skipped, unless no row can be deserialized at all
+/// (e.g. because the delimiter doesn't match). Then the first error is returned.
+pub fn read_csv_from_file_with_options<T: DeserializeOwned>(
+    file_path: &Path,
+    options: &CsvOptions,
+) -> Result<Vec<T>, IOError> {
+    let mut objects = Vec::new();
+    let mut first_error = None;
+    for row in stream_csv_from_file_with_options(file_path, options)? {
+        match row {
+            Ok(object) => objects.push(object),
+            Err(why) => {
+                first_error.get_or_insert(why);
+            }
+        }
+    }
+
+    match first_error {
+        Some(why) if objects.is_empty() => Err(why),
+        _ => Ok(objects),
+    }
+}
+
+/// Lazily read the rows of the csv file, decompressing files whose name ends in `.gz`.
+///
+/// In contrast to [`read_csv_from_file`], only one row is kept in memory at a time
+/// and rows that can't be deserialized are reported as errors.
+pub fn stream_csv_from_file<T: DeserializeOwned>(
+    file_path: &Path,
+) -> Result<impl Iterator<Item = Result<T, IOError>>, IOError> {
+    stream_csv_from_file_with_options(file_path, &CsvOptions::default())
+}
+
+/// Lazily read the rows of the csv file according to `options` like [`stream_csv_from_file`].
+pub fn stream_csv_from_file_with_options<T: DeserializeOwned>(
+    file_path: &Path,
+    options: &CsvOptions,
+) -> Result<impl Iterator<Item = Result<T, IOError>>, IOError> {
     let display: String = file_path.display().to_string();
 
     // open file for reading
@@ -125,11 +432,217 @@ pub fn read_csv_from_file<T: DeserializeOwned>(file_path: &Path) -> Result<Vec<T
         Ok(file) => file,
     };
 
-    let mut reader = csv::ReaderBuilder::new()
-        .has_headers(true)
-        .from_reader(file);
+    let file: Box<dyn Read> = if is_gzip(file_path) {
+        Box::new(MultiGzDecoder::new(file))
+    } else {
+        Box::new(file)
+    };
+    let reader = options.reader_builder().has_headers(true).from_reader(file);
+
+    Ok(reader.into_deserialize::<T>().map(move |row| {
+        row.map_err(|why| IOError::CannotDeserialize(display.clone(), why.to_string()))
+    }))
+}
+
+/// File format of measurement files.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum MeasurementFormat {
+    /// Comma separated values with a header row
+    #[default]
+    Csv,
+    /// One json object per line, see [`jsonl`]
+    JsonLines,
+}
+
+impl MeasurementFormat {
+    /// The file extension of measurement files in this format.
+    pub fn extension(self) -> &'static str {
+        match self {
+            MeasurementFormat::Csv => "csv",
+            MeasurementFormat::JsonLines => "jsonl",
+        }
+    }
+
+    /// The format matching the file extension, if there is one.
+    pub fn from_extension(extension: &str) -> Option<Self> {
+        match extension {
+            "csv" => Some(MeasurementFormat::Csv),
+            "jsonl" => Some(MeasurementFormat::JsonLines),
+            _ => None,
+        }
+    }
+}
+
+/// Appends rows to a measurement file while keeping the file open between rows.
+///
+/// Files ending in `.jsonl` are written as [JSON Lines](MeasurementFormat::JsonLines), all others as csv.
+/// Always writes uncompressed data, even to files ending in `.gz`.
+///
+/// Rows are written to a temporary copy `{file}.tmp` of the file and flushed whenever `flush_every` rows are buffered.
+/// Only [`finish`](Self::finish) replaces the file by the temporary file, so the file itself is never left
+/// partially written. If the process is interrupted, the temporary file still holds all flushed rows,
+/// and the next writer for the same file recovers them before appending.
+///
+/// Rows are only appended to csv files whose header matches their field names.
+pub struct MeasurementWriter {
+    writer: MeasurementSink,
+    file_path: PathBuf,
+    temp_path: PathBuf,
+    flush_every: usize,
+    unflushed: usize,
+    // the header of the existing csv rows, checked against the first written row
+    existing_header: Option<csv::StringRecord>,
+}
+
+enum MeasurementSink {
+    Csv(Box<csv::Writer<File>>),
+    JsonLines(BufWriter<File>),
+}
+
+impl MeasurementWriter {
+    /// Prepares appending to the given file, writing a csv header with the field names if the file is new.
+    pub fn new(file_path: &Path, flush_every: usize) -> Result<Self, IOError> {
+        ensure_parent_folder_exists(file_path)?;
+
+        let mut temp_path = file_path.as_os_str().to_owned();
+        temp_path.push(".tmp");
+        let temp_path = PathBuf::from(temp_path);
+        let display: String = temp_path.display().to_string();
+
+        // recover the flushed rows of a writer that was never finished;
+        // an empty temporary file has no rows to recover and would replace the file's header
+        match std::fs::metadata(&temp_path) {
+            Ok(metadata) if metadata.len() == 0 => std::fs::remove_file(&temp_path)
+                .map_err(|why| IOError::CannotWrite(display.clone(), why.to_string()))?,
+            Ok(_) => {
+                std::fs::rename(&temp_path, file_path).map_err(|why| {
+                    IOError::CannotWrite(file_path.display().to_string(), why.to_string())
+                })?;
+                log::warn!("Recovered unfinished measurements from {}.", display);
+            }
+            Err(_) => {}
+        }
+
+        // start from a copy of the existing rows, if there are any;
+        // files without any content need a header just like new ones
+        let (file, has_headers) = match std::fs::copy(file_path, &temp_path) {
+            Ok(bytes) => (OpenOptions::new().append(true).open(&temp_path), bytes == 0),
+            Err(why) if why.kind() == ErrorKind::NotFound => (File::create(&temp_path), true),
+            Err(why) => return Result::Err(IOError::CannotWrite(display, why.to_string())),
+        };
+        let file = file.map_err(|why| IOError::CannotWrite(display.clone(), why.to_string()))?;
+
+        let format = file_path
+            .extension()
+            .and_then(|e| e.to_str())
+            .and_then(MeasurementFormat::from_extension)
+            .unwrap_or_default();
+        let existing_header = match (format, has_headers) {
+            (MeasurementFormat::Csv, false) => {
+                Some(read_csv_header(file_path, &CsvOptions::default())?)
+            }
+            _ => None,
+        };
+        let writer = match format {
+            MeasurementFormat::Csv => MeasurementSink::Csv(Box::new(
+                csv::WriterBuilder::new()
+                    .has_headers(has_headers)
+                    .from_writer(file),
+            )),
+            MeasurementFormat::JsonLines => MeasurementSink::JsonLines(BufWriter::new(file)),
+        };
+
+        Ok(Self {
+            writer,
+            file_path: file_path.to_path_buf(),
+            temp_path,
+            flush_every,
+            unflushed: 0,
+            existing_header,
+        })
+    }
+
+    /// Appends a row, flushing the buffered rows if there are `flush_every` of them.
+    ///
+    /// Fails if the row's fields don't match the header of the existing csv rows.
+    pub fn write<T: Serialize>(&mut self, row: &T) -> Result<(), IOError> {
+        if let Some(header) = self.existing_header.take() {
+            ensure_header_matches(
+                &header,
+                row,
+                &HeaderMode::Auto,
+                &self.file_path.display().to_string(),
+            )?;
+        }
+
+        let display = || self.temp_path.display().to_string();
+        match &mut self.writer {
+            MeasurementSink::Csv(writer) => writer
+                .serialize(row)
+                .map_err(|why| IOError::CannotSerialize(display(), why.to_string()))?,
+            MeasurementSink::JsonLines(writer) => {
+                serde_json::to_writer(&mut *writer, row)
+                    .map_err(|why| IOError::CannotSerialize(display(), why.to_string()))?;
+                writer
+                    .write_all(b"\n")
+                    .map_err(|why| IOError::CannotWrite(display(), why.to_string()))?;
+            }
+        }
+        self.unflushed += 1;
+
+        if self.unflushed >= self.flush_every {
+            self.flush()?;
+        }
+        Ok(())
+    }
 
-    Ok(reader.deserialize::<T>().filter_map(Result::ok).collect())
+    /// Writes all buffered rows to the temporary file.
+    pub fn flush(&mut self) -> Result<(), IOError> {
+        match &mut self.writer {
+            MeasurementSink::Csv(writer) => writer.flush(),
+            MeasurementSink::JsonLines(writer) => writer.flush(),
+        }
+        .map_err(|why| {
+            IOError::CannotWrite(self.temp_path.display().to_string(), why.to_string())
+        })?;
+        self.unflushed = 0;
+        Ok(())
+    }
+
+    /// Flushes all remaining rows and replaces the file by the temporary file.
+    pub fn finish(mut self) -> Result<(), IOError> {
+        self.flush()?;
+        std::fs::rename(&self.temp_path, &self.file_path).map_err(|why| {
+            IOError::CannotWrite(self.file_path.display().to_string(), why.to_string())
+        })
+    }
+
+    /// Drops all rows written by this writer and removes the temporary file, leaving the file unchanged.
+    pub fn discard(self) -> Result<(), IOError> {
+        let Self {
+            writer, temp_path, ..
+        } = self;
+        drop(writer);
+        std::fs::remove_file(&temp_path)
+            .map_err(|why| IOError::CannotWrite(temp_path.display().to_string(), why.to_string()))
+    }
+}
+
+/// Reads the whole file into a string.
+fn read_file_to_string(file_path: &Path) -> Result<String, IOError> {
+    let display: String = file_path.display().to_string();
+
+    // open file for reading
+    let mut file = match OpenOptions::new().read(true).open(file_path) {
+        Err(why) => return Result::Err(IOError::CannotRead(display, why.to_string())),
+        Ok(file) => file,
+    };
+
+    let mut file_content = String::new();
+    if let Err(why) = file.read_to_string(&mut file_content) {
+        return Result::Err(IOError::CannotRead(display, why.to_string()));
+    }
+    Ok(file_content)
 }
 
 fn ensure_parent_folder_exists(file_path: &Path) -> Result<(), IOError> {
@@ -146,3 +659,404 @@ fn ensure_parent_folder_exists(file_path: &Path) -> Result<(), IOError> {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod test {
+    use serde::Deserialize;
+
+    use super::*;
+
+    #[derive(Serialize, Deserialize, Debug, PartialEq)]
+    struct Measurement {
+        total_time: u64,
+        delay_avg: f64,
+    }
+
+    #[derive(Serialize)]
+    struct ExtendedMeasurement {
+        total_time: u64,
+        delay_avg: f64,
+        failed: bool,
+    }
+
+    #[derive(Deserialize, Debug, PartialEq)]
+    struct ShortMeasurement {
+        #[serde(rename = "t")]
+        total_time: u64,
+        #[serde(rename = "d")]
+        delay_avg: f64,
+    }
+
+    #[test]
+    fn test_named_headers() {
+        let file_path = std::env::temp_dir().join("exp_lib_test_named_headers.csv");
+        let _ = std::fs::remove_file(&file_path);
+
+        let header_mode = HeaderMode::Named(vec![String::from("t"), String::from("d")]);
+        let measurements = [
+            Measurement {
+                total_time: 42,
+                delay_avg: 1.5,
+            },
+            Measurement {
+                total_time: 7,
+                delay_avg: 0.25,
+            },
+        ];
+        append_csv_to_file_with_header_mode(&file_path, &measurements[..1], &header_mode).unwrap();
+        append_csv_to_file_with_header_mode(&file_path, &measurements[1..], &header_mode).unwrap();
+
+        let content = std::fs::read_to_string(&file_path).unwrap();
+        let read: Vec<ShortMeasurement> = read_csv_from_file(&file_path).unwrap();
+        std::fs::remove_file(&file_path).unwrap();
+
+        assert_eq!(content.lines().next(), Some("t,d"));
+        assert_eq!(
+            read,
+            [
+                ShortMeasurement {
+                    total_time: 42,
+                    delay_avg: 1.5
+                },
+                ShortMeasurement {
+                    total_time: 7,
+                    delay_avg: 0.25
+                }
+            ]
+        );
+    }
+
+    #[test]
+    fn test_named_headers_count_mismatch() {
+        let file_path = std::env::temp_dir().join("exp_lib_test_named_headers_mismatch.csv");
+        let _ = std::fs::remove_file(&file_path);
+
+        let header_mode = HeaderMode::Named(vec![String::from("t")]);
+        let result = append_csv_to_file_with_header_mode(
+            &file_path,
+            &[Measurement {
+                total_time: 42,
+                delay_avg: 1.5,
+            }],
+            &header_mode,
+        );
+
+        assert!(matches!(result, Err(IOError::CannotSerialize(_, _))));
+        assert!(!file_path.exists());
+    }
+
+    #[test]
+    fn test_append_header_mismatch() {
+        let file_path = std::env::temp_dir().join("exp_lib_test_append_header_mismatch.csv");
+        let _ = std::fs::remove_file(&file_path);
+        append_csv_to_file(&file_path, &[measurement(1)]).unwrap();
+
+        let result = append_csv_to_file(
+            &file_path,
+            &[ExtendedMeasurement {
+                total_time: 2,
+                delay_avg: 0.5,
+                failed: false,
+            }],
+        );
+        let read: Vec<Measurement> = read_csv_from_file(&file_path).unwrap();
+        std::fs::remove_file(&file_path).unwrap();
+
+        assert!(matches!(result, Err(IOError::CannotSerialize(_, _))));
+        assert_eq!(read, [measurement(1)]);
+    }
+
+    fn measurement(total_time: u64) -> Measurement {
+        Measurement {
+            total_time,
+            delay_avg: 0.5,
+        }
+    }
+
+    #[test]
+    fn test_measurement_writer() {
+        let file_path = std::env::temp_dir().join("exp_lib_test_measurement_writer.csv");
+        let _ = std::fs::remove_file(&file_path);
+        append_csv_to_file(&file_path, &[measurement(1)]).unwrap();
+
+        let mut writer = MeasurementWriter::new(&file_path, 2).unwrap();
+        writer.write(&measurement(2)).unwrap();
+        writer.write(&measurement(3)).unwrap();
+        writer.write(&measurement(4)).unwrap();
+        let unfinished: Vec<Measurement> = read_csv_from_file(&file_path).unwrap();
+        writer.finish().unwrap();
+
+        let finished: Vec<Measurement> = read_csv_from_file(&file_path).unwrap();
+        std::fs::remove_file(&file_path).unwrap();
+
+        assert_eq!(unfinished, [measurement(1)]);
+        assert_eq!(finished, [1, 2, 3, 4].map(measurement));
+    }
+
+    #[test]
+    fn test_measurement_writer_jsonl() {
+        let file_path = std::env::temp_dir().join("exp_lib_test_measurement_writer.jsonl");
+        let _ = std::fs::remove_file(&file_path);
+        jsonl::append_jsonl_to_file(&file_path, &[measurement(1)]).unwrap();
+
+        let mut writer = MeasurementWriter::new(&file_path, 1).unwrap();
+        writer.write(&measurement(2)).unwrap();
+        writer.finish().unwrap();
+
+        let read: Vec<Measurement> = jsonl::read_jsonl_from_file(&file_path).unwrap();
+        std::fs::remove_file(&file_path).unwrap();
+
+        assert_eq!(read, [1, 2].map(measurement));
+    }
+
+    #[test]
+    fn test_measurement_writer_interrupted() {
+        let file_path =
+            std::env::temp_dir().join("exp_lib_test_measurement_writer_interrupted.csv");
+        let temp_path =
+            std::env::temp_dir().join("exp_lib_test_measurement_writer_interrupted.csv.tmp");
+        let _ = std::fs::remove_file(&file_path);
+
+        let mut writer = MeasurementWriter::new(&file_path, 2).unwrap();
+        writer.write(&measurement(1)).unwrap();
+        writer.write(&measurement(2)).unwrap();
+        writer.write(&measurement(3)).unwrap();
+        // simulate a crash: neither the buffered row nor the rename reach the disk
+        std::mem::forget(writer);
+
+        let flushed: Vec<Measurement> = read_csv_from_file(&temp_path).unwrap();
+        std::fs::remove_file(&temp_path).unwrap();
+
+        assert!(!file_path.exists());
+        assert_eq!(flushed, [measurement(1), measurement(2)]);
+    }
+
+    #[test]
+    fn test_measurement_writer_recovers_interrupted() {
+        let file_path = std::env::temp_dir().join("exp_lib_test_measurement_writer_recover.csv");
+        let _ = std::fs::remove_file(&file_path);
+        append_csv_to_file(&file_path, &[measurement(1)]).unwrap();
+
+        let mut writer = MeasurementWriter::new(&file_path, 1).unwrap();
+        writer.write(&measurement(2)).unwrap();
+        // simulate a crash after the row was flushed
+        std::mem::forget(writer);
+
+        let mut writer = MeasurementWriter::new(&file_path, 1).unwrap();
+        writer.write(&measurement(3)).unwrap();
+        writer.finish().unwrap();
+
+        let read: Vec<Measurement> = read_csv_from_file(&file_path).unwrap();
+        std::fs::remove_file(&file_path).unwrap();
+
+        assert_eq!(read, [1, 2, 3].map(measurement));
+    }
+
+    #[test]
+    fn test_measurement_writer_forgotten_before_first_write() {
+        let file_path = std::env::temp_dir().join("exp_lib_test_measurement_writer_forgotten.csv");
+        let temp_path =
+            std::env::temp_dir().join("exp_lib_test_measurement_writer_forgotten.csv.tmp");
+        let _ = std::fs::remove_file(&file_path);
+
+        // simulate a crash before the first row: only an empty temporary file remains
+        std::mem::forget(MeasurementWriter::new(&file_path, 1).unwrap());
+
+        let mut writer = MeasurementWriter::new(&file_path, 1).unwrap();
+        writer.write(&measurement(1)).unwrap();
+        writer.write(&measurement(2)).unwrap();
+        writer.finish().unwrap();
+
+        let read: Vec<Measurement> = read_csv_from_file(&file_path).unwrap();
+        std::fs::remove_file(&file_path).unwrap();
+
+        assert!(!temp_path.exists());
+        assert_eq!(read, [1, 2].map(measurement));
+    }
+
+    #[test]
+    fn test_measurement_writer_header_mismatch() {
+        let file_path = std::env::temp_dir().join("exp_lib_test_measurement_writer_mismatch.csv");
+        let _ = std::fs::remove_file(&file_path);
+        append_csv_to_file(&file_path, &[measurement(1)]).unwrap();
+
+        let mut writer = MeasurementWriter::new(&file_path, 1).unwrap();
+        let result = writer.write(&ExtendedMeasurement {
+            total_time: 2,
+            delay_avg: 0.5,
+            failed: false,
+        });
+        writer.discard().unwrap();
+
+        let read: Vec<Measurement> = read_csv_from_file(&file_path).unwrap();
+        std::fs::remove_file(&file_path).unwrap();
+
+        assert!(matches!(result, Err(IOError::CannotSerialize(_, _))));
+        assert_eq!(read, [measurement(1)]);
+    }
+
+    #[test]
+    fn test_measurement_writer_discard() {
+        let file_path = std::env::temp_dir().join("exp_lib_test_measurement_writer_discard.csv");
+        let temp_path =
+            std::env::temp_dir().join("exp_lib_test_measurement_writer_discard.csv.tmp");
+        let _ = std::fs::remove_file(&file_path);
+        append_csv_to_file(&file_path, &[measurement(1)]).unwrap();
+
+        let mut writer = MeasurementWriter::new(&file_path, 1).unwrap();
+        writer.write(&measurement(2)).unwrap();
+        writer.discard().unwrap();
+
+        let read: Vec<Measurement> = read_csv_from_file(&file_path).unwrap();
+        std::fs::remove_file(&file_path).unwrap();
+
+        assert!(!temp_path.exists());
+        assert_eq!(read, [measurement(1)]);
+    }
+
+    #[test]
+    fn test_round_decimal_field() {
+        assert_eq!(round_decimal_field("4.5", 3), "4.5");
+        assert_eq!(round_decimal_field("3.14159265", 3), "3.14");
+        assert_eq!(round_decimal_field("0.0006666666", 3), "0.000667");
+        assert_eq!(round_decimal_field("1234567.8", 3), "1230000");
+        assert_eq!(round_decimal_field("123456789", 3), "123456789");
+        assert_eq!(round_decimal_field("enum-iqs", 3), "enum-iqs");
+    }
+
+    #[test]
+    fn test_float_precision() {
+        let file_path = std::env::temp_dir().join("exp_lib_test_float_precision.csv");
+        let _ = std::fs::remove_file(&file_path);
+
+        let options = CsvOptions {
+            float_precision: Some(3),
+            ..Default::default()
+        };
+        let measurements = [
+            Measurement {
+                total_time: 123456,
+                delay_avg: 4.5,
+            },
+            Measurement {
+                total_time: 7,
+                delay_avg: 2.0 / 3.0,
+            },
+        ];
+        append_csv_to_file_with_options(&file_path, &measurements, &HeaderMode::Auto, &options)
+            .unwrap();
+
+        let content = std::fs::read_to_string(&file_path).unwrap();
+        std::fs::remove_file(&file_path).unwrap();
+
+        assert_eq!(content, "total_time,delay_avg\n123456,4.5\n7,0.667\n");
+    }
+
+    #[test]
+    fn test_gzip_round_trip() {
+        let file_path = std::env::temp_dir().join("exp_lib_test_gzip_round_trip.csv.gz");
+        let _ = std::fs::remove_file(&file_path);
+
+        append_csv_to_file(&file_path, &[measurement(1), measurement(2)]).unwrap();
+        append_csv_to_file(&file_path, &[measurement(3)]).unwrap();
+
+        let mut bytes = Vec::new();
+        File::open(&file_path)
+            .unwrap()
+            .read_to_end(&mut bytes)
+            .unwrap();
+        let read: Vec<Measurement> = read_csv_from_file(&file_path).unwrap();
+        std::fs::remove_file(&file_path).unwrap();
+
+        // gzip magic number
+        assert_eq!(bytes[..2], [0x1f, 0x8b]);
+        assert_eq!(read, [1, 2, 3].map(measurement));
+    }
+
+    #[test]
+    fn test_gzip_named_headers() {
+        let file_path = std::env::temp_dir().join("exp_lib_test_gzip_named_headers.csv.gz");
+        let _ = std::fs::remove_file(&file_path);
+
+        let header_mode = HeaderMode::Named(vec![String::from("t"), String::from("d")]);
+        append_csv_to_file_with_header_mode(&file_path, &[measurement(1)], &header_mode).unwrap();
+        append_csv_to_file_with_header_mode(&file_path, &[measurement(2)], &header_mode).unwrap();
+
+        let read: Vec<ShortMeasurement> = read_csv_from_file(&file_path).unwrap();
+        std::fs::remove_file(&file_path).unwrap();
+
+        assert_eq!(
+            read,
+            [1, 2].map(|total_time| ShortMeasurement {
+                total_time,
+                delay_avg: 0.5
+            })
+        );
+    }
+
+    #[test]
+    fn test_semicolon_delimiter() {
+        let file_path = std::env::temp_dir().join("exp_lib_test_semicolon_delimiter.csv");
+        let _ = std::fs::remove_file(&file_path);
+
+        let options = CsvOptions {
+            delimiter: b';',
+            ..Default::default()
+        };
+        let measurements = [1, 2].map(measurement);
+        append_csv_to_file_with_options(&file_path, &measurements, &HeaderMode::Auto, &options)
+            .unwrap();
+
+        let content = std::fs::read_to_string(&file_path).unwrap();
+        let read: Vec<Measurement> = read_csv_from_file_with_options(&file_path, &options).unwrap();
+        let read_with_commas = read_csv_from_file::<Measurement>(&file_path);
+        std::fs::remove_file(&file_path).unwrap();
+
+        assert_eq!(content, "total_time;delay_avg\n1;0.5\n2;0.5\n");
+        assert_eq!(read, measurements);
+        // with the wrong delimiter, no row can be deserialized
+        assert!(matches!(
+            read_with_commas,
+            Err(IOError::CannotDeserialize(_, _))
+        ));
+    }
+
+    #[test]
+    fn test_stream_csv_from_file() {
+        let file_path = std::env::temp_dir().join("exp_lib_test_stream_csv.csv");
+        let _ = std::fs::remove_file(&file_path);
+
+        let n = 100_000;
+        let measurements: Vec<Measurement> = (0..n).map(measurement).collect();
+        append_csv_to_file(&file_path, &measurements).unwrap();
+        // a row that can't be deserialized
+        append_csv_to_file(&file_path, &[("not a number", 0.5)]).unwrap();
+
+        let mut count = 0;
+        let mut errors = 0;
+        for (i, row) in stream_csv_from_file::<Measurement>(&file_path)
+            .unwrap()
+            .enumerate()
+        {
+            match row {
+                Ok(m) => {
+                    assert_eq!(m, measurement(i as u64));
+                    count += 1;
+                }
+                Err(IOError::CannotDeserialize(_, _)) => errors += 1,
+                Err(e) => panic!("Unexpected error {e}"),
+            }
+        }
+        std::fs::remove_file(&file_path).unwrap();
+
+        assert_eq!(count, n);
+        assert_eq!(errors, 1);
+    }
+
+    #[test]
+    fn test_stream_csv_missing_file() {
+        let file_path = std::env::temp_dir().join("exp_lib_test_stream_csv_missing.csv");
+        let result = stream_csv_from_file::<Measurement>(&file_path);
+        assert!(matches!(result, Err(IOError::CannotRead(_, _))));
+    }
+}
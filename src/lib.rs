@@ -1,5 +1,6 @@
 pub mod algorithms;
 pub mod data_structures;
 pub mod experiments;
+pub mod helpers;
 pub mod io;
 pub mod random_generators;
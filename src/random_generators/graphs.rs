@@ -1,3 +1,11 @@
+mod barabasi_albert;
+mod complete;
 mod dag;
+mod gnm;
+mod grid;
 
+pub use barabasi_albert::*;
+pub use complete::*;
 pub use dag::*;
+pub use gnm::*;
+pub use grid::*;
@@ -0,0 +1,145 @@
+use serde::{de::DeserializeOwned, Serialize};
+
+use crate::{
+    data_structures::graphs::{DirectedAdjacencyArraysGraph, DirectedEdgeListGraph, Index},
+    experiments::ExperimentGenerator,
+    random_generators::numbers::Rng,
+};
+
+/// A random undirected scale-free graph in the Barabási–Albert model \[1\].
+///
+/// The graph starts as a clique of `attachments + 1` vertices. Each further vertex is connected to
+/// `attachments` distinct existing vertices, chosen with probability proportional to their degree
+/// (preferential attachment). The resulting graph is connected and has
+/// `attachments * (num_vertices - attachments - 1) + (attachments + 1) * attachments / 2` undirected edges,
+/// each stored as both directed edges.
+///
+/// \[1\] A.-L. Barabási and R. Albert, “Emergence of Scaling in Random Networks,” Science, vol. 286, no. 5439, pp. 509–512, 1999, doi: [10.1126/science.286.5439.509](https://doi.org/10.1126/science.286.5439.509).
+pub struct BarabasiAlbert<'a, I: Index + Serialize + DeserializeOwned> {
+    pub rng: &'a mut dyn Rng,
+    pub num_vertices: I,
+    /// The number of edges of each new vertex, often called `m`
+    pub attachments: I,
+}
+
+impl<I: Index + Serialize + DeserializeOwned> BarabasiAlbert<'_, I> {
+    /// Generate the experiment instance.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `attachments` is 0 or `num_vertices` is not larger than `attachments`.
+    fn generate_edge_list_graph(&mut self) -> DirectedEdgeListGraph<I> {
+        let n = self.num_vertices.index();
+        let m = self.attachments.index();
+        assert!(m >= 1, "Each new vertex needs at least one edge.");
+        assert!(n > m, "The initial clique needs {} vertices.", m + 1);
+
+        let mut edges: Vec<(I, I)> = Vec::new();
+        // every vertex appears once per incident edge, so sampling from this list is proportional to degree
+        let mut endpoints: Vec<I> = Vec::new();
+        let mut add_edge = |u: usize, v: usize, endpoints: &mut Vec<I>| {
+            edges.push((I::new(u), I::new(v)));
+            edges.push((I::new(v), I::new(u)));
+            endpoints.push(I::new(u));
+            endpoints.push(I::new(v));
+        };
+
+        for u in 0..=m {
+            for v in u + 1..=m {
+                add_edge(u, v, &mut endpoints);
+            }
+        }
+
+        let mut targets: Vec<usize> = Vec::with_capacity(m);
+        for v in m + 1..n {
+            targets.clear();
+            while targets.len() < m {
+                let target = endpoints[self.rng.next_usize(0..=endpoints.len() - 1)].index();
+                if !targets.contains(&target) {
+                    targets.push(target);
+                }
+            }
+            for &target in &targets {
+                add_edge(v, target, &mut endpoints);
+            }
+        }
+
+        DirectedEdgeListGraph::new(self.num_vertices, edges.into_boxed_slice())
+    }
+}
+
+impl<I: Index + Serialize + DeserializeOwned> ExperimentGenerator<DirectedAdjacencyArraysGraph<I>>
+    for BarabasiAlbert<'_, I>
+{
+    fn path() -> String {
+        String::from("./data/graphs/barabasi_albert/")
+    }
+
+    fn file_name(&self) -> String {
+        format!(
+            "{}_{}_{}",
+            self.num_vertices,
+            self.attachments,
+            self.rng.state_id()
+        )
+    }
+
+    /// Generate the experiment instance.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `attachments` is 0 or `num_vertices` is not larger than `attachments`.
+    fn generate(&mut self) -> DirectedAdjacencyArraysGraph<I> {
+        DirectedAdjacencyArraysGraph::from(&self.generate_edge_list_graph())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        algorithms::graphs::spanning_forest::SpanningForest,
+        data_structures::graphs::{DirectedGraph, Direction},
+        random_generators::numbers::TaillardLCG,
+    };
+
+    use super::*;
+
+    #[test]
+    fn test_barabasi_albert() {
+        for (n, m) in [(100u32, 1u32), (100, 3), (4, 3), (500, 5)] {
+            let mut rng = TaillardLCG::from_seed(42);
+            let graph = BarabasiAlbert {
+                rng: &mut rng,
+                num_vertices: n,
+                attachments: m,
+            }
+            .generate();
+
+            let clique_edges = (m + 1) * m / 2;
+            assert_eq!(graph.num_vertices(), n);
+            assert_eq!(graph.num_edges(), 2 * (m * (n - m - 1) + clique_edges));
+            assert_eq!(graph.num_loops(), 0);
+            for v in 0..n {
+                assert!(graph.degree(v, Direction::OUT) >= m);
+                assert_eq!(
+                    graph.degree(v, Direction::OUT),
+                    graph.degree(v, Direction::IN)
+                );
+            }
+            // connected, i.e. a single spanning tree
+            assert_eq!(SpanningForest::new(&graph).count(), n as usize - 1);
+        }
+    }
+
+    #[test]
+    fn test_file_name() {
+        let mut rng = TaillardLCG::from_seed(42);
+        let generator = BarabasiAlbert {
+            rng: &mut rng,
+            num_vertices: 100u32,
+            attachments: 3,
+        };
+        assert_eq!(generator.file_name(), "100_3_TLCG-42");
+        crate::experiments::validate_file_name(&generator.file_name()).unwrap();
+    }
+}
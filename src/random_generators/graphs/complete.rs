@@ -0,0 +1,76 @@
+use serde::{de::DeserializeOwned, Serialize};
+
+use crate::{
+    data_structures::graphs::{DirectedAdjacencyArraysGraph, DirectedEdgeListGraph, Index},
+    experiments::ExperimentGenerator,
+};
+
+/// The complete undirected graph on `num_vertices` vertices without loops.
+///
+/// This is a deterministic generator; each undirected edge is stored as both directed edges.
+pub struct CompleteGraph<I: Index + Serialize + DeserializeOwned> {
+    pub num_vertices: I,
+}
+
+impl<I: Index + Serialize + DeserializeOwned> CompleteGraph<I> {
+    fn generate_edge_list_graph(&self) -> DirectedEdgeListGraph<I> {
+        let edges: Vec<(I, I)> = I::new(0)
+            .range(self.num_vertices)
+            .flat_map(|u| {
+                I::new(0)
+                    .range(self.num_vertices)
+                    .filter(move |&v| v != u)
+                    .map(move |v| (u, v))
+            })
+            .collect();
+
+        DirectedEdgeListGraph::new(self.num_vertices, edges.into_boxed_slice())
+    }
+}
+
+impl<I: Index + Serialize + DeserializeOwned> ExperimentGenerator<DirectedAdjacencyArraysGraph<I>>
+    for CompleteGraph<I>
+{
+    fn path() -> String {
+        String::from("./data/graphs/complete/")
+    }
+
+    /// As the graph is deterministic, the last part of the file name is `det` instead of an rng state.
+    fn file_name(&self) -> String {
+        format!("{}_complete_det", self.num_vertices)
+    }
+
+    fn generate(&mut self) -> DirectedAdjacencyArraysGraph<I> {
+        DirectedAdjacencyArraysGraph::from(&self.generate_edge_list_graph())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::data_structures::graphs::{DirectedGraph, Direction};
+
+    use super::*;
+
+    #[test]
+    fn test_complete_graph() {
+        for n in [1u32, 2, 10] {
+            let graph = CompleteGraph { num_vertices: n }.generate();
+
+            assert_eq!(graph.num_vertices(), n);
+            assert_eq!(graph.num_edges(), n * n.saturating_sub(1));
+            assert_eq!(graph.num_loops(), 0);
+            for v in 0..n {
+                assert_eq!(graph.degree(v, Direction::OUT), n - 1);
+            }
+        }
+    }
+
+    #[test]
+    fn test_file_name() {
+        let generator = CompleteGraph {
+            num_vertices: 10u32,
+        };
+        assert_eq!(generator.file_name(), "10_complete_det");
+        crate::experiments::validate_file_name(&generator.file_name()).unwrap();
+    }
+}
@@ -68,3 +68,54 @@ impl<I: Index + Serialize + DeserializeOwned> ExperimentGenerator<DirectedAdjace
         DirectedAdjacencyArraysGraph::from(&self.generate_edge_list_graph())
     }
 }
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        algorithms::graphs::scc::has_cycle, data_structures::graphs::DirectedGraph,
+        random_generators::numbers::TaillardLCG,
+    };
+
+    use super::*;
+
+    #[test]
+    fn test_acyclic() {
+        let mut rng = TaillardLCG::from_seed(42);
+        for edge_probability in [0.0, 0.1, 0.5, 1.0] {
+            let graph: DirectedAdjacencyArraysGraph<u32> = DAG {
+                rng: &mut rng,
+                num_vertices: 50,
+                edge_probability,
+            }
+            .generate();
+
+            assert_eq!(graph.num_vertices(), 50);
+            assert!(!has_cycle(&graph));
+        }
+    }
+
+    #[test]
+    fn test_complete_dag() {
+        let mut rng = TaillardLCG::from_seed(42);
+        let graph: DirectedAdjacencyArraysGraph<u32> = DAG {
+            rng: &mut rng,
+            num_vertices: 20,
+            edge_probability: 1.0,
+        }
+        .generate();
+
+        // every pair of vertices is connected in exactly one direction
+        assert_eq!(graph.num_edges(), 20 * 19 / 2);
+    }
+
+    #[test]
+    fn test_file_name() {
+        let mut rng = TaillardLCG::from_seed(42);
+        let generator: DAG<u32> = DAG {
+            rng: &mut rng,
+            num_vertices: 50,
+            edge_probability: 0.25,
+        };
+        crate::experiments::validate_file_name(&generator.file_name()).unwrap();
+    }
+}
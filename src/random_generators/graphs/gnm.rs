@@ -0,0 +1,169 @@
+use std::collections::HashSet;
+
+use serde::{de::DeserializeOwned, Serialize};
+
+use crate::{
+    data_structures::graphs::{DirectedAdjacencyArraysGraph, DirectedEdgeListGraph, Index},
+    experiments::ExperimentGenerator,
+    random_generators::{numbers::Rng, permutations::Permutation},
+};
+
+/// A random undirected graph in the Erdős–Rényi G(n,m) model.
+///
+/// Exactly `num_edges` distinct undirected edges without loops are chosen uniformly at random.
+/// Each undirected edge is stored as both directed edges.
+pub struct GnmUndirected<'a, I: Index + Serialize + DeserializeOwned> {
+    pub rng: &'a mut dyn Rng,
+    pub num_vertices: I,
+    pub num_edges: usize,
+}
+
+impl<I: Index + Serialize + DeserializeOwned> GnmUndirected<'_, I> {
+    /// Generate the experiment instance.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `num_edges` is larger than the number of edges of the complete graph.
+    fn generate_edge_list_graph(&mut self) -> DirectedEdgeListGraph<I> {
+        let n = self.num_vertices.index();
+        let max_edges = n * n.saturating_sub(1) / 2;
+        assert!(
+            self.num_edges <= max_edges,
+            "A simple graph with {} vertices has at most {} edges.",
+            n,
+            max_edges
+        );
+
+        // Rejection sampling needs many attempts for dense graphs,
+        // so then sample the edges that are missing instead.
+        let undirected_edges = if 2 * self.num_edges <= max_edges {
+            self.sample_distinct_pairs(self.num_edges)
+        } else {
+            let missing: HashSet<(usize, usize)> = self
+                .sample_distinct_pairs(max_edges - self.num_edges)
+                .into_iter()
+                .collect();
+            let mut present: Vec<(usize, usize)> = (0..n)
+                .flat_map(|u| (u + 1..n).map(move |v| (u, v)))
+                .filter(|pair| !missing.contains(pair))
+                .collect();
+            Permutation::shuffle(self.rng, &mut present);
+            present
+        };
+
+        let edges: Vec<(I, I)> = undirected_edges
+            .into_iter()
+            .flat_map(|(u, v)| [(I::new(u), I::new(v)), (I::new(v), I::new(u))])
+            .collect();
+
+        DirectedEdgeListGraph::new(self.num_vertices, edges.into_boxed_slice())
+    }
+
+    /// Sample `count` distinct pairs `(u, v)` with `u < v` in the order they were drawn.
+    fn sample_distinct_pairs(&mut self, count: usize) -> Vec<(usize, usize)> {
+        let n = self.num_vertices.index();
+        let mut seen: HashSet<(usize, usize)> = HashSet::with_capacity(count);
+        let mut pairs: Vec<(usize, usize)> = Vec::with_capacity(count);
+        while pairs.len() < count {
+            let u = self.rng.next_usize(0..=n - 1);
+            let v = self.rng.next_usize(0..=n - 1);
+            if u != v && seen.insert((u.min(v), u.max(v))) {
+                pairs.push((u.min(v), u.max(v)));
+            }
+        }
+        pairs
+    }
+}
+
+impl<I: Index + Serialize + DeserializeOwned> ExperimentGenerator<DirectedAdjacencyArraysGraph<I>>
+    for GnmUndirected<'_, I>
+{
+    fn path() -> String {
+        String::from("./data/graphs/gnm/")
+    }
+
+    fn file_name(&self) -> String {
+        format!(
+            "{}_{}_{}",
+            self.num_vertices,
+            self.num_edges,
+            self.rng.state_id()
+        )
+    }
+
+    /// Generate the experiment instance.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `num_edges` is larger than the number of edges of the complete graph.
+    fn generate(&mut self) -> DirectedAdjacencyArraysGraph<I> {
+        DirectedAdjacencyArraysGraph::from(&self.generate_edge_list_graph())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        data_structures::graphs::{DirectedGraph, Direction},
+        random_generators::numbers::TaillardLCG,
+    };
+
+    use super::*;
+
+    #[test]
+    fn test_gnm() {
+        // sparse graphs use rejection sampling, dense ones sample the missing edges
+        for (n, m) in [
+            (50u32, 0),
+            (50, 100),
+            (50, 1000),
+            (50, 1225),
+            (2, 1),
+            (1, 0),
+        ] {
+            let mut rng = TaillardLCG::from_seed(42);
+            let graph = GnmUndirected {
+                rng: &mut rng,
+                num_vertices: n,
+                num_edges: m,
+            }
+            .generate();
+
+            assert_eq!(graph.num_vertices(), n);
+            assert_eq!(graph.num_edges(), 2 * m as u32);
+            assert_eq!(graph.num_loops(), 0);
+
+            let mut edges: Vec<(u32, u32)> = (0..n)
+                .flat_map(|u| graph.neighbors(u, Direction::OUT).map(move |v| (u, v)))
+                .collect();
+            edges.sort_unstable();
+            edges.dedup();
+            assert_eq!(edges.len(), 2 * m, "Duplicate edges.");
+            assert!(edges.iter().all(|&(u, v)| graph.has_edge(v, u)));
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_too_many_edges() {
+        let mut rng = TaillardLCG::from_seed(42);
+        GnmUndirected {
+            rng: &mut rng,
+            num_vertices: 5u32,
+            num_edges: 11,
+        }
+        .generate();
+    }
+
+    #[test]
+    fn test_file_name() {
+        let mut rng = TaillardLCG::from_seed(42);
+        let generator: GnmUndirected<u32> = GnmUndirected {
+            rng: &mut rng,
+            num_vertices: 100,
+            num_edges: 250,
+        };
+        assert_eq!(generator.file_name(), "100_250_TLCG-42");
+        crate::experiments::validate_file_name(&generator.file_name()).unwrap();
+    }
+}
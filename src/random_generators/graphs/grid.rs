@@ -0,0 +1,140 @@
+use serde::{de::DeserializeOwned, Serialize};
+
+use crate::{
+    data_structures::graphs::{DirectedAdjacencyArraysGraph, DirectedEdgeListGraph, Index},
+    experiments::ExperimentGenerator,
+};
+
+/// A two-dimensional grid graph, optionally wrapped into a torus.
+///
+/// This is a deterministic generator: vertex `r * cols + c` is connected to its (up to) four neighbors
+/// `(r ± 1, c)` and `(r, c ± 1)`. If `wrap` is set, the first and last row and column are connected as well,
+/// as long as this neither creates loops nor parallel edges, i.e. only for at least three rows or columns.
+/// Each undirected edge is stored as both directed edges.
+pub struct Grid2D<I: Index + Serialize + DeserializeOwned> {
+    pub rows: I,
+    pub cols: I,
+    pub wrap: bool,
+}
+
+impl<I: Index + Serialize + DeserializeOwned> Grid2D<I> {
+    fn generate_edge_list_graph(&self) -> DirectedEdgeListGraph<I> {
+        let (rows, cols) = (self.rows.index(), self.cols.index());
+        let vertex = |r: usize, c: usize| I::new(r * cols + c);
+
+        let mut edges: Vec<(I, I)> = Vec::new();
+        let mut add_edge = |u: I, v: I| {
+            edges.push((u, v));
+            edges.push((v, u));
+        };
+
+        for r in 0..rows {
+            for c in 0..cols {
+                if c + 1 < cols {
+                    add_edge(vertex(r, c), vertex(r, c + 1));
+                } else if self.wrap && cols > 2 {
+                    add_edge(vertex(r, c), vertex(r, 0));
+                }
+                if r + 1 < rows {
+                    add_edge(vertex(r, c), vertex(r + 1, c));
+                } else if self.wrap && rows > 2 {
+                    add_edge(vertex(r, c), vertex(0, c));
+                }
+            }
+        }
+
+        DirectedEdgeListGraph::new(I::new(rows * cols), edges.into_boxed_slice())
+    }
+}
+
+impl<I: Index + Serialize + DeserializeOwned> ExperimentGenerator<DirectedAdjacencyArraysGraph<I>>
+    for Grid2D<I>
+{
+    fn path() -> String {
+        String::from("./data/graphs/grid/")
+    }
+
+    /// As the graph is deterministic, the last part of the file name is `det` instead of an rng state.
+    fn file_name(&self) -> String {
+        format!(
+            "{}_{}x{}-{}_det",
+            self.rows.index() * self.cols.index(),
+            self.rows,
+            self.cols,
+            if self.wrap { "torus" } else { "grid" }
+        )
+    }
+
+    fn generate(&mut self) -> DirectedAdjacencyArraysGraph<I> {
+        DirectedAdjacencyArraysGraph::from(&self.generate_edge_list_graph())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::data_structures::graphs::{DirectedGraph, Direction};
+
+    use super::*;
+
+    #[test]
+    fn test_grid() {
+        let graph = Grid2D {
+            rows: 3u32,
+            cols: 3,
+            wrap: false,
+        }
+        .generate();
+
+        assert_eq!(graph.num_vertices(), 9);
+        // 6 horizontal and 6 vertical edges, each in both directions
+        assert_eq!(graph.num_edges(), 2 * 12);
+        assert_eq!(graph.degree(0, Direction::OUT), 2);
+        assert_eq!(graph.degree(1, Direction::OUT), 3);
+        assert_eq!(graph.degree(4, Direction::OUT), 4);
+        assert!(graph.has_edge(4, 5) && graph.has_edge(5, 4) && graph.has_edge(4, 7));
+        assert!(!graph.has_edge(2, 3));
+    }
+
+    #[test]
+    fn test_torus() {
+        let graph = Grid2D {
+            rows: 3u32,
+            cols: 3,
+            wrap: true,
+        }
+        .generate();
+
+        assert_eq!(graph.num_vertices(), 9);
+        // 9 horizontal and 9 vertical edges, each in both directions
+        assert_eq!(graph.num_edges(), 2 * 18);
+        for v in 0..9 {
+            assert_eq!(graph.degree(v, Direction::OUT), 4);
+        }
+        assert!(graph.has_edge(2, 0) && graph.has_edge(6, 0));
+    }
+
+    #[test]
+    fn test_narrow_torus() {
+        // wrapping two columns would duplicate the edges between them
+        let graph = Grid2D {
+            rows: 1u32,
+            cols: 2,
+            wrap: true,
+        }
+        .generate();
+
+        assert_eq!(graph.num_edges(), 2);
+        assert_eq!(graph.num_loops(), 0);
+    }
+
+    #[test]
+    fn test_file_name() {
+        let generator = Grid2D {
+            rows: 3u32,
+            cols: 4,
+            wrap: true,
+        };
+        assert_eq!(generator.file_name(), "12_3x4-torus_det");
+        crate::experiments::validate_file_name(&generator.file_name()).unwrap();
+    }
+}
@@ -1,3 +1,5 @@
+use crate::{data_structures::graphs::Index, experiments::ExperimentGenerator};
+
 use super::numbers::Rng;
 
 pub struct Permutation {}
@@ -34,3 +36,161 @@ impl Permutation {
         permutation
     }
 }
+
+/// A random permutation of `0..num_elements` with a given number of inversions.
+///
+/// An inversion is a pair of positions `i < j` with `p[i] > p[j]`; the identity has 0 inversions,
+/// the reversed identity the maximum of `n(n-1)/2` inversions.
+/// Larger targets are capped at this maximum.
+///
+/// The permutation is built from a random inversion vector (Lehmer code) whose entries sum up to the target:
+/// entry `i` is the number of elements right of position `i` that are smaller than the element at position `i`.
+/// Note that this does *not* sample uniformly from all permutations with the target number of inversions.
+pub struct KInversions<'a> {
+    pub rng: &'a mut dyn Rng,
+    pub num_elements: u32,
+    pub target_inversions: u64,
+}
+
+impl ExperimentGenerator<Vec<u32>> for KInversions<'_> {
+    fn path() -> String {
+        String::from("./data/permutations/k_inversions/")
+    }
+
+    fn file_name(&self) -> String {
+        format!(
+            "{}_{}_{}",
+            self.num_elements,
+            self.target_inversions,
+            self.rng.state_id()
+        )
+    }
+
+    fn generate(&mut self) -> Vec<u32> {
+        let n = u64::from(self.num_elements);
+        let mut remaining = self.target_inversions.min(n * n.saturating_sub(1) / 2);
+
+        // choose the inversion vector
+        let mut lehmer_code = Vec::with_capacity(self.num_elements.index());
+        for i in 0..n {
+            // maximum number of inversions that positions i+1..n can still contribute
+            let max_right = (n - 1 - i) * (n - 1 - i).saturating_sub(1) / 2;
+            let min_here = remaining.saturating_sub(max_right);
+            let max_here = remaining.min(n - 1 - i);
+            let c = self.rng.next_usize(min_here as usize..=max_here as usize) as u64;
+            remaining -= c;
+            lehmer_code.push(c as usize);
+        }
+
+        // decode: the element at position i is the c_i-th smallest element not used yet
+        let mut unused = FenwickTree::full(self.num_elements.index());
+        lehmer_code
+            .into_iter()
+            .map(|c| {
+                let element = unused.find_kth(c);
+                unused.remove(element);
+                u32::new(element)
+            })
+            .collect()
+    }
+}
+
+/// A binary indexed tree over 0/1 entries to find the k-th remaining element in O(log n).
+struct FenwickTree {
+    tree: Vec<usize>,
+}
+
+impl FenwickTree {
+    /// Creates a tree where all `n` entries are present.
+    fn full(n: usize) -> Self {
+        // tree[i] (1-indexed) covers the lowbit(i) entries ending in i
+        let tree = (0..=n).map(|i| i & i.wrapping_neg()).collect();
+        Self { tree }
+    }
+
+    fn remove(&mut self, element: usize) {
+        let mut i = element + 1;
+        while i < self.tree.len() {
+            self.tree[i] -= 1;
+            i += i & i.wrapping_neg();
+        }
+    }
+
+    /// Returns the k-th (0-based) present element.
+    fn find_kth(&self, k: usize) -> usize {
+        let mut position = 0;
+        let mut remaining = k + 1;
+        let mut step = (self.tree.len() - 1).next_power_of_two();
+        while step > 0 {
+            let next = position + step;
+            if next < self.tree.len() && self.tree[next] < remaining {
+                position = next;
+                remaining -= self.tree[next];
+            }
+            step /= 2;
+        }
+        position
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::random_generators::numbers::TaillardLCG;
+
+    use super::*;
+
+    /// Count the inversions of `a` with merge sort.
+    fn count_inversions(a: &[u32]) -> u64 {
+        if a.len() < 2 {
+            return 0;
+        }
+        let (left, right) = a.split_at(a.len() / 2);
+        let mut inversions = count_inversions(left) + count_inversions(right);
+
+        let mut left = left.to_vec();
+        let mut right = right.to_vec();
+        left.sort_unstable();
+        right.sort_unstable();
+        let mut j = 0;
+        for x in left.iter() {
+            while j < right.len() && right[j] < *x {
+                j += 1;
+            }
+            // all of right[..j] are smaller than x, but right of it
+            inversions += j as u64;
+        }
+        inversions
+    }
+
+    fn generate(num_elements: u32, target_inversions: u64) -> Vec<u32> {
+        let mut rng = TaillardLCG::from_seed(42);
+        KInversions {
+            rng: &mut rng,
+            num_elements,
+            target_inversions,
+        }
+        .generate()
+    }
+
+    #[test]
+    fn test_zero_inversions() {
+        assert_eq!(generate(100, 0), (0..100).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_max_inversions() {
+        assert_eq!(generate(100, 4950), (0..100).rev().collect::<Vec<_>>());
+        assert_eq!(generate(100, 1_000_000), (0..100).rev().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_k_inversions() {
+        for target in [1, 17, 1000, 2475, 4949] {
+            let mut permutation = generate(100, target);
+            assert_eq!(count_inversions(&permutation), target);
+
+            permutation.sort_unstable();
+            assert_eq!(permutation, (0..100).collect::<Vec<_>>());
+        }
+    }
+}
@@ -2,3 +2,34 @@
 
 pub mod flow_shop;
 pub mod single_machine;
+
+use serde::{de::DeserializeOwned, Serialize};
+
+use super::numbers::Rng;
+
+/// Job weights the scheduling generators can draw at random.
+///
+/// Each generator produces instances with any such weight type. Weights are drawn after all other values,
+/// so the weighted instance for a seed has the same jobs as the unweighted one.
+pub trait RandomWeight: Default + Serialize + DeserializeOwned {
+    /// Subfolder of the generator's path for instances with this kind of weights.
+    const FOLDER: &'static str;
+
+    fn random(rng: &mut dyn Rng) -> Self;
+}
+
+/// Unweighted jobs, no values are drawn.
+impl RandomWeight for () {
+    const FOLDER: &'static str = "";
+
+    fn random(_rng: &mut dyn Rng) -> Self {}
+}
+
+/// Weights chosen uniformly at random from the integer interval `1..=99`, just like processing times.
+impl RandomWeight for i32 {
+    const FOLDER: &'static str = "weighted/";
+
+    fn random(rng: &mut dyn Rng) -> Self {
+        rng.next_i32(1..=99)
+    }
+}
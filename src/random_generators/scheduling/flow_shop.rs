@@ -7,9 +7,12 @@ use crate::{
     random_generators::numbers::Rng,
 };
 
+use super::RandomWeight;
+
 /// A flow shop generated according to Taillard \[1, 2\].
 ///
 /// Each processing time is chosen uniformly at random from the integer interval `1..=99`.
+/// Job weights are drawn afterwards, see [`RandomWeight`].
 ///
 /// \[1\] E. Taillard, „Benchmarks for basic scheduling problems“, European Journal of Operational Research, Bd. 64, Nr. 2, S. 278–285, Jan. 1993, doi: [10.1016/0377-2217(93)90182-M](https://doi.org/10.1016/0377-2217(93)90182-M).<br>
 /// \[2\] E. Taillard, “Scheduling instances,” Éric Taillard’s page. \[Online\]. Available: <http://mistic.heig-vd.ch/taillard/problemes.dir/ordonnancement.dir/ordonnancement.html>.
@@ -19,17 +22,19 @@ pub struct Taillard<'a> {
     pub machines: u32,
 }
 
-impl ExperimentGenerator<SchedulingInstance<FlowShop, i32>> for Taillard<'_> {
+impl<Weight: RandomWeight>
+    ExperimentGenerator<SchedulingInstance<FlowShop, i32, (), (), (), Weight>> for Taillard<'_>
+{
     fn path() -> String {
-        String::from("./data/scheduling/flowshop/taillard/")
+        format!("./data/scheduling/flowshop/taillard/{}", Weight::FOLDER)
     }
 
     fn file_name(&self) -> String {
         format!("{}_{}_{}", self.jobs, self.machines, self.rng.state_id(),)
     }
 
-    fn generate(&mut self) -> SchedulingInstance<FlowShop, i32> {
-        let mut job_data: Vec<Job<i32>> = (0..self.jobs)
+    fn generate(&mut self) -> SchedulingInstance<FlowShop, i32, (), (), (), Weight> {
+        let mut job_data: Vec<Job<i32, (), (), Weight>> = (0..self.jobs)
             .map(|id| Job::for_num_operations(id, self.machines))
             .collect();
 
@@ -38,6 +43,9 @@ impl ExperimentGenerator<SchedulingInstance<FlowShop, i32>> for Taillard<'_> {
                 j.operations[i.index()] = self.rng.next_i32(1..=99);
             }
         }
+        for j in &mut job_data {
+            j.weight = Weight::random(self.rng);
+        }
 
         SchedulingInstance {
             environment: FlowShop {
@@ -80,7 +88,7 @@ mod test {
         let m = 5;
 
         let mut rng = TaillardLCG::from_seed(seed);
-        let instance = Taillard {
+        let instance: SchedulingInstance<FlowShop, i32> = Taillard {
             rng: &mut rng,
             jobs: n,
             machines: m,
@@ -96,4 +104,32 @@ mod test {
             }
         }
     }
+
+    #[test]
+    fn test_weighted_taillard() {
+        let seed = 873654221;
+        let mut rng = TaillardLCG::from_seed(seed);
+        let unweighted: SchedulingInstance<FlowShop, i32> = Taillard {
+            rng: &mut rng,
+            jobs: 20,
+            machines: 5,
+        }
+        .generate();
+        let mut rng = TaillardLCG::from_seed(seed);
+        let weighted: SchedulingInstance<FlowShop, i32, (), (), (), i32> = Taillard {
+            rng: &mut rng,
+            jobs: 20,
+            machines: 5,
+        }
+        .generate();
+
+        for (u, w) in unweighted.jobs.iter().zip(&weighted.jobs) {
+            assert_eq!(u.operations, w.operations);
+            assert!((1..=99).contains(&w.weight));
+        }
+        assert_ne!(
+            <Taillard as ExperimentGenerator<SchedulingInstance<FlowShop, i32>>>::path(),
+            <Taillard as ExperimentGenerator<SchedulingInstance<FlowShop, i32, (), (), (), i32>>>::path()
+        );
+    }
 }
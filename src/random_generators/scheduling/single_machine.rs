@@ -10,25 +10,31 @@ use crate::{
 use num::{rational::Ratio, ToPrimitive, Zero};
 use num_bigint::BigInt;
 
+use super::RandomWeight;
+
 /// A single-machine scheduling instance with DAG precedences.
 ///
 /// This corresponds to problems of the type 1|prec| in standardized scheduling notation.
 ///
 /// Each processing time is chosen uniformly at random from the integer interval `1..=99`.
 /// The DAG is chosen uniformly at random in the G(n,p) model with the given edge_probability.
+/// Job weights are drawn afterwards, see [`RandomWeight`].
 pub struct WithPrecedences<'a> {
     pub rng: &'a mut dyn Rng,
     pub jobs: u32,
     pub edge_probability: f64,
 }
 
-impl
+impl<Weight: RandomWeight>
     ExperimentGenerator<
-        SchedulingInstance<SingleMachine, i32, (), (), DirectedAdjacencyArraysGraph<u32>>,
+        SchedulingInstance<SingleMachine, i32, (), (), DirectedAdjacencyArraysGraph<u32>, Weight>,
     > for WithPrecedences<'_>
 {
     fn path() -> String {
-        String::from("./data/scheduling/single_machine/with_prec/")
+        format!(
+            "./data/scheduling/single_machine/with_prec/{}",
+            Weight::FOLDER
+        )
     }
 
     fn file_name(&self) -> String {
@@ -42,8 +48,9 @@ impl
 
     fn generate(
         &mut self,
-    ) -> SchedulingInstance<SingleMachine, i32, (), (), DirectedAdjacencyArraysGraph<u32>> {
-        let mut job_data: Vec<Job<i32>> = (0..self.jobs)
+    ) -> SchedulingInstance<SingleMachine, i32, (), (), DirectedAdjacencyArraysGraph<u32>, Weight>
+    {
+        let mut job_data: Vec<Job<i32, (), (), Weight>> = (0..self.jobs)
             .map(|id| Job::for_num_operations(id, 1))
             .collect();
 
@@ -58,6 +65,10 @@ impl
         }
         .generate();
 
+        for j in &mut job_data {
+            j.weight = Weight::random(self.rng);
+        }
+
         SchedulingInstance {
             environment: SingleMachine,
             jobs: job_data,
@@ -79,15 +90,22 @@ impl
 /// where no jobs are available (`release_spread` > 1). Note that the later can only happen,
 /// if the sum of processing times is not too large, as the release time is stored as `i32` and
 /// we thus cap the maximum release time at `i32::MAX`.
+/// Job weights are drawn afterwards, see [`RandomWeight`].
 pub struct WithReleaseTimes<'a> {
     pub rng: &'a mut dyn Rng,
     pub jobs: u32,
     pub release_spread: f64,
 }
 
-impl ExperimentGenerator<SchedulingInstance<SingleMachine, i32, (), i32>> for WithReleaseTimes<'_> {
+impl<Weight: RandomWeight>
+    ExperimentGenerator<SchedulingInstance<SingleMachine, i32, (), i32, (), Weight>>
+    for WithReleaseTimes<'_>
+{
     fn path() -> String {
-        String::from("./data/scheduling/single_machine/with_release_times/")
+        format!(
+            "./data/scheduling/single_machine/with_release_times/{}",
+            Weight::FOLDER
+        )
     }
 
     fn file_name(&self) -> String {
@@ -99,8 +117,8 @@ impl ExperimentGenerator<SchedulingInstance<SingleMachine, i32, (), i32>> for Wi
         )
     }
 
-    fn generate(&mut self) -> SchedulingInstance<SingleMachine, i32, (), i32> {
-        let mut job_data: Vec<Job<i32, (), i32>> = (0..self.jobs)
+    fn generate(&mut self) -> SchedulingInstance<SingleMachine, i32, (), i32, (), Weight> {
+        let mut job_data: Vec<Job<i32, (), i32, Weight>> = (0..self.jobs)
             .map(|id| Job::for_num_operations(id, 1))
             .collect();
 
@@ -121,6 +139,9 @@ impl ExperimentGenerator<SchedulingInstance<SingleMachine, i32, (), i32>> for Wi
         for j in &mut job_data {
             j.release_time = self.rng.next_i32(0..=max_release_time);
         }
+        for j in &mut job_data {
+            j.weight = Weight::random(self.rng);
+        }
 
         SchedulingInstance {
             environment: SingleMachine,